@@ -1,5 +1,5 @@
 use std::ops::Div;
-use std::ops::{Add, Sub, Mul};
+use std::ops::{Add, Sub, Mul, Rem, AddAssign, RemAssign};
 
 use crate::core;
 use crate::assert_err;
@@ -7,10 +7,38 @@ use crate::assert_err;
 
 const IMPLICIT_SIGN: bool  = false;
 
+/// Maximum number of characters (digits and dot) of a value fully written by [BigNum::summary]
+const SUMMARY_MAX_DIGITS: usize = 30;
+/// Number of characters kept at each end of the value when [BigNum::summary] shortens it
+const SUMMARY_EDGE_DIGITS: usize = 10;
+
+/// Maximum number of factors multiplied one by one by [BigNum::par_product] (larger slices are split)
+const PRODUCT_TREE_LEAF_LEN: usize = 16;
+
 /// Maximum number of decimal digits when perfoming a division on BigNums
 pub const FLOAT_PRECISION: i64 = 15;
 
 
+/// The ways of rounding a [BigNum] (see [BigNum::round])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round toward zero (truncation)
+    Down,
+    /// Round away from zero
+    Up,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceiling,
+    /// Round to the nearest value, halves are rounded away from zero
+    HalfUp,
+    /// Round to the nearest value, halves are rounded toward zero
+    HalfDown,
+    /// Round to the nearest value, halves are rounded toward the even neighbour (banker's rounding)
+    HalfEven
+}
+
+
 /// Represents an arbitrary long/precise decimal number
 #[derive(Clone, Debug)]
 pub struct BigNum {
@@ -51,11 +79,36 @@ impl BigNum {
         res.clean();
         Ok(res)
     }
+
+
+
+    /// Returns a BigNum holding exactly the given values, without checking nor cleaning them.  
+    /// Only meant to test [BigNum::validate]: the other functions expect valid BigNums.
+    #[doc(hidden)]
+    pub fn new_unchecked(negative: bool, abs: Vec<u8>, power: u32) -> BigNum {BigNum {negative, abs, power}}
     
     /// Return a BigNum representing zero (0)
     pub fn zero() -> BigNum {BigNum {negative: false, abs: vec![0], power: 0}}
     /// Return a BigNum representing one (1)
     pub fn one() -> BigNum {BigNum {negative: false, abs: vec![1], power: 0}}
+    /// Return a BigNum representing 10^exponent
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::ten_pow(3), BigNum::from(1000));
+    /// assert_eq!(BigNum::ten_pow(-3), BigNum::from_string("0.001").unwrap());
+    /// assert_eq!(BigNum::ten_pow(0), BigNum::one());
+    /// ```
+    pub fn ten_pow(exponent: i32) -> BigNum {
+        if exponent < 0 {return BigNum {negative: false, abs: vec![1], power: exponent.unsigned_abs()}}
+
+        let mut abs = vec![0; exponent as usize];
+        abs.push(1);
+        BigNum {negative: false, abs, power: 0}
+    }
 
 
     /// Return true if the BigNum is < 0.  
@@ -72,6 +125,141 @@ impl BigNum {
     /// assert_eq!(BigNum::from_string("-0").unwrap().is_negative(), false);         // -0 is converted to 0 automatically
     /// ```
     pub fn is_negative(&self) -> bool {return self.negative;}
+
+
+    /// Return true if the BigNum is > 0.  
+    /// For any BigNum, exactly one of [BigNum::is_positive], [BigNum::is_negative] and [BigNum::is_zero] returns true.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// for n in ["12.5", "-0.001", "0"] {
+    ///     let n = BigNum::from_string(n).unwrap();
+    ///     let classes = [n.is_positive(), n.is_negative(), n.is_zero()];
+    ///     assert_eq!(classes.iter().filter(|c| **c).count(), 1);
+    /// }
+    /// 
+    /// assert_eq!(BigNum::from_string("12.5").unwrap().is_positive(), true);
+    /// assert_eq!(BigNum::from_string("-0.001").unwrap().is_positive(), false);
+    /// assert_eq!(BigNum::zero().is_positive(), false);
+    /// ```
+    pub fn is_positive(&self) -> bool {!self.negative && !self.is_zero()}
+
+
+    /// Return true if the BigNum is a zero marked as negative (`-0`).  
+    /// This is only a diagnostic: every BigNum built by the API is cleaned, so this should never return true.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n = BigNum::from_parts(true, vec![0, 0], 3).unwrap();  // -0.000
+    /// 
+    /// assert!(!n.is_negative_zero_representation());
+    /// assert!(!n.is_negative());
+    /// assert_eq!(n.into_parts(), (false, vec![0], 0));
+    /// assert!(!BigNum::from_string("-0.0").unwrap().is_negative_zero_representation());
+    /// assert!(!BigNum::zero().opposite().is_negative_zero_representation());
+    /// ```
+    pub fn is_negative_zero_representation(&self) -> bool {self.negative && self.is_zero()}
+
+
+    /// Return true if both BigNums are negative, or both are non-negative (zero is considered non-negative)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("-24892.242").unwrap();
+    /// let n2 = BigNum::from_string("-1").unwrap();
+    /// let n3 = BigNum::from_string("1332").unwrap();
+    /// 
+    /// assert!(n1.same_sign(&n2));
+    /// assert!(!n1.same_sign(&n3));
+    /// assert!(n3.same_sign(&BigNum::zero()));
+    /// assert!(!BigNum::zero().same_sign(&n2));
+    /// ```
+    pub fn same_sign(&self, other: &BigNum) -> bool {self.negative == other.negative}
+
+
+    /// Return the power of the BigNum, i.e the number of digits after the dot
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::from_string("-24892.242").unwrap().power(), 3);
+    /// assert_eq!(BigNum::from_string("1332").unwrap().power(), 0);
+    /// assert_eq!(BigNum::from_string("0.50").unwrap().power(), 1);
+    /// ```
+    pub fn power(&self) -> u32 {self.power}
+
+
+
+    /// Return copies of the values describing the BigNum: whether it is negative, its digits
+    /// (from least to most significant) and its power. This is the inverse of [BigNum::new]
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n = BigNum::from_string("-43.21").unwrap();
+    /// let (negative, abs, power) = n.decompose();
+    /// 
+    /// assert_eq!((negative, abs.clone(), power), (true, vec![1, 2, 3, 4], 2));
+    /// assert_eq!(BigNum::new(negative, abs, power).unwrap(), n);
+    /// ```
+    pub fn decompose(&self) -> (bool, Vec<u8>, u32) {
+        (self.negative, self.abs.clone(), self.power)
+    }
+
+
+
+    /// Consume the BigNum and return the values describing it (see [BigNum::decompose]),
+    /// without copying its digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = BigNum::from_string("-43.21").unwrap();
+    /// let (negative, abs, power) = n.clone().into_parts();
+    /// assert_eq!((negative, abs.clone(), power), (true, vec![1, 2, 3, 4], 2));
+    ///
+    /// // the digits buffer is moved in and out, not copied
+    /// let ptr = abs.as_ptr();
+    /// let rebuilt = BigNum::from_parts(negative, abs, power).unwrap();
+    /// assert_eq!(rebuilt, n);
+    /// assert_eq!(rebuilt.into_parts().1.as_ptr(), ptr);
+    /// ```
+    pub fn into_parts(self) -> (bool, Vec<u8>, u32) {
+        (self.negative, self.abs, self.power)
+    }
+
+
+
+    /// Build a BigNum from the values describing it, taking ownership of the digits.
+    /// This is the inverse of [BigNum::into_parts], and behaves exactly like [BigNum::new]
+    /// (the digits are checked and the result is cleaned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_parts(false, vec![0, 5, 2, 0], 2).unwrap(), BigNum::from_string("2.5").unwrap());
+    /// assert!(BigNum::from_parts(false, vec![12], 0).is_err());
+    /// ```
+    pub fn from_parts(negative: bool, abs: Vec<u8>, power: u32) -> Result<BigNum, String> {
+        BigNum::new(negative, abs, power)
+    }
   
 
     /// Returns a new BigNum, cleaned, from the given string.
@@ -137,606 +325,3765 @@ impl BigNum {
 
 
 
-    /// Returns a BigNum from a i32
-    /// The function simply convert the i32 into a string, then calls [Self::from_string]
+    /// Returns a new BigNum, cleaned, from the characters read from `reader`.
+    /// The input is read progressively, so very long numbers can be parsed without holding their whole string in memory.
+    /// The accepted format is the same as [BigNum::from_string].
     /// 
     /// # Arguments
     /// 
-    /// * `n` - the number to convert into a BigNum
+    /// * `reader` - the source of the ASCII characters representing the number
+    /// * `decimal_places_hint` - the expected number of digits after the dot, if known. It is only used to reserve memory
     /// 
     /// # Examples
-    ///
+    /// 
     /// ```
     /// use pilosa::BigNum;
     /// 
-    /// let number = BigNum::from_i32(134).unwrap();
-    /// let number = BigNum::from_i32(0).unwrap();
-    /// let number = BigNum::from_i32(-242952842).unwrap();
+    /// assert_eq!(BigNum::from_reader("-24895.25243".as_bytes(), None), BigNum::from_string("-24895.25243"));
+    /// assert_eq!(BigNum::from_reader(" + 0.5 ".as_bytes(), Some(1)), BigNum::from_string("0.5"));
+    /// assert!(BigNum::from_reader("1.2.3".as_bytes(), None).is_err());
+    /// assert!(BigNum::from_reader("12-3".as_bytes(), None).is_err());
+    /// assert!(BigNum::from_reader("".as_bytes(), None).is_err());
+    /// 
+    /// // multi-megabyte number
+    /// let digits = format!("{}.{}", "123456789".repeat(200_000), "987654321".repeat(200_000));
+    /// let n = BigNum::from_reader(std::io::Cursor::new(digits.as_bytes()), Some(1_800_000)).unwrap();
+    /// assert_eq!(n.power(), 1_800_000);
+    /// assert_eq!(Ok(n), BigNum::from_string(&digits));
     /// ```
-    pub fn from_i32(n: i32) -> Result<BigNum, String> {
-        BigNum::from_string(&n.to_string())
+    pub fn from_reader<R: std::io::Read>(reader: R, decimal_places_hint: Option<u32>) -> Result<BigNum, String> {
+        use std::io::Read;
+
+        // digits from most to least significant
+        let mut digits: Vec<u8> = Vec::with_capacity(decimal_places_hint.unwrap_or(0) as usize + 1);
+        let mut negative = None;
+        let mut power: Option<u32> = None; // some => dot found
+        let mut empty = true;
+
+        for byte in std::io::BufReader::new(reader).bytes() {
+            let byte = byte.map_err(|e| format!("Could not read the number: {}", e))?;
+
+            match byte {
+                b' ' => continue,
+                b'-' | b'+' if empty => negative = Some(byte == b'-'),
+                b'.' if power.is_none() => power = Some(0),
+                b'0'..=b'9' => {
+                    digits.push(byte - b'0');
+                    if let Some(p) = power.as_mut() {*p += 1;}
+                },
+                _ => return Err("Invalid format".to_string())
+            }
+
+            empty = false;
+        }
+
+        assert_err!(!empty, "Empty string");
+
+        digits.reverse();
+        BigNum::new(negative.unwrap_or(IMPLICIT_SIGN), digits, power.unwrap_or(0))
     }
-    /// Returns a BigNum from a f64
-    /// The function simply convert the f64 into a string, then calls [Self::from_string]
+
+
+
+
+    /// Returns a new BigNum from a string using digit grouping, like `"1,234,567.89"`.
+    /// The grouping separators are checked: they must separate groups of exactly 3 digits
+    /// (the first group can be shorter), and cannot appear after the decimal separator.
     /// 
     /// # Arguments
     /// 
-    /// * `n` - the number to convert into a BigNum
+    /// * `s` - A string representing a number, with grouped digits
+    /// * `group_sep` - The character separating the groups of digits (ex: `','`)
+    /// * `decimal_sep` - The character separating the integer part from the decimal part (ex: `'.'`)
     /// 
     /// # Examples
-    ///
+    /// 
     /// ```
     /// use pilosa::BigNum;
     /// 
-    /// let number = BigNum::from_f64(134.2452).unwrap();
-    /// let number = BigNum::from_f64(0.0).unwrap();
-    /// let number = BigNum::from_f64(-2.4249252952842).unwrap();
+    /// let expected = BigNum::from_string("1234567.89").unwrap();
+    /// 
+    /// assert_eq!(BigNum::from_grouped_string("1,234,567.89", ',', '.'), Ok(expected.clone()));
+    /// assert_eq!(BigNum::from_grouped_string("1.234.567,89", '.', ','), Ok(expected));
+    /// assert_eq!(BigNum::from_grouped_string("-999", ',', '.'), Ok(BigNum::from(-999)));
+    /// assert!(BigNum::from_grouped_string("12,34.5", ',', '.').is_err());
+    /// assert!(BigNum::from_grouped_string("1,234.567,8", ',', '.').is_err());
     /// ```
-    pub fn from_f64(n: f64) -> Result<BigNum, String> {
-        BigNum::from_string(&n.to_string())
-    }
+    pub fn from_grouped_string(s: &str, group_sep: char, decimal_sep: char) -> Result<BigNum, String> {
+        assert_err!(group_sep != decimal_sep, "The group and decimal separators must be different");
 
+        let (integer, decimal) = match s.split_once(decimal_sep) {
+            Some((i, d)) => (i, Some(d)),
+            None => (s, None)
+        };
 
+        // the sign is not part of the first group
+        let digits = integer.trim_start_matches(['-', '+']);
+        let sign = &integer[..integer.len() - digits.len()];
 
-    
+        let groups: Vec<&str> = digits.split(group_sep).collect();
+        for (i, g) in groups.iter().enumerate() {
+            let valid = if i == 0 {!g.is_empty() && g.len() <= 3} else {g.len() == 3};
+            assert_err!(valid || groups.len() == 1, "Invalid digit grouping");
+        }
 
-    /// Modify the given bignums so they have the same power.
-    /// Does not change their values
-    fn same_power(n1: &mut BigNum, n2: &mut BigNum) {
-        if n1.power < n2.power {n1.with_power(n2.power)}
-        else {n2.with_power(n1.power)}
+        let mut string = format!("{}{}", sign, groups.concat());
+        if let Some(d) = decimal {
+            assert_err!(!d.contains(group_sep), "Invalid digit grouping");
+            string.push('.');
+            string.push_str(d);
+        }
+
+        BigNum::from_string(&string)
     }
 
 
 
 
-    /// unclean one of the given BigNum so that both share the same amount of digits.
-    /// Does not change their values
-    fn same_digit_amount(n1: &mut BigNum, n2: &mut BigNum) {
-        while n1.abs.len() < n2.abs.len() {n1.abs.push(0);}
-        while n1.abs.len() > n2.abs.len() {n2.abs.push(0);}
+    /// Returns a BigNum from a i32
+    /// The digits are directly extracted from the i32 (see the [From] implementations for the other integer types)
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - the number to convert into a BigNum
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let number = BigNum::from_i32(134).unwrap();
+    /// let number = BigNum::from_i32(0).unwrap();
+    /// let number = BigNum::from_i32(-242952842).unwrap();
+    /// 
+    /// // the minimum values of signed integers are supported too
+    /// assert_eq!(BigNum::from(i8::MIN), BigNum::from_string("-128").unwrap());
+    /// assert_eq!(BigNum::from_i32(i32::MIN), BigNum::from_string("-2147483648"));
+    /// assert_eq!(BigNum::from(i64::MIN), BigNum::from_string("-9223372036854775808").unwrap());
+    /// assert_eq!(BigNum::from(u64::MAX), BigNum::from_string("18446744073709551615").unwrap());
+    /// ```
+    pub fn from_i32(n: i32) -> Result<BigNum, String> {
+        Ok(BigNum::from(n))
     }
 
 
 
-    /// Return true if n is 0 (or -0, but it should not happen)
-    pub fn is_zero(&self) -> bool {
-        if self.abs.is_empty() {panic!("Error: BigNum does not have any digit. Please report this error");}
-        self.abs.len() == 1 && self.abs[0] == 0
+    /// Returns 1 if `b` is true, 0 otherwise. See [BigNum::to_bool]
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::from_bool(true), BigNum::one());
+    /// assert_eq!(BigNum::from_bool(false), BigNum::zero());
+    /// ```
+    pub fn from_bool(b: bool) -> BigNum {
+        if b {BigNum::one()} else {BigNum::zero()}
     }
 
 
+    /// Returns the BigNum `numer / 10^scale`.
+    /// The value is built directly from the digits of `numer`, so it is exact and no division is needed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `numer` - the digits of the number, as an integer
+    /// * `scale` - the number of digits of `numer` after the dot
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::from_int_and_scale(7, 2), BigNum::from_string("0.07").unwrap());
+    /// assert_eq!(BigNum::from_int_and_scale(-125, 3), BigNum::from_string("-0.125").unwrap());
+    /// assert_eq!(BigNum::from_int_and_scale(1500, 2), BigNum::from(15));
+    /// assert_eq!(BigNum::from_int_and_scale(0, 5), BigNum::zero());
+    /// ```
+    pub fn from_int_and_scale(numer: i128, scale: u32) -> BigNum {
+        let mut res = BigNum::from(numer);
+        res.power = scale;
+        res.clean();
+        res
+    }
 
-    /// Clean the BigNum from any useless information:
-    /// - useless significant zeroes (ex: 010 -> 10)
-    /// - Reduce the power as much possible by removing useless decimal zeroes `(0.10 => 0.1)`
-    /// - Prevent the representation of `-0`
-    fn clean(&mut self) {
-        if self.abs.is_empty() {
-            if self.negative {self.negative = false;}
-            return;
-        }
 
-        // decimal zeroes (12.120 => 12.12)
-        let check = |x: &mut BigNum| x.abs.first().is_some() && x.abs.first().unwrap() == &0 && x.power > 0;
-        while check(self) {
-            self.power -= 1;
-            self.abs.remove(0);
-        }
 
-        core::ub_clean(&mut self.abs);
-        
-        // prevent -0
-        if self.is_zero() && self.negative {
-            self.negative = false;
+
+    /// Returns a new BigNum built from a sequence of digits, given from the most to the least significant.
+    /// Like [BigNum::new], the result is cleaned.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `negative` - whether the number is negative or not
+    /// * `most_significant_first` - the digits of the number, from most to least significant
+    /// * `decimal_places` - how many of those digits are after the dot
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::from_digit_iter(false, [1, 2, 3, 4], 2), BigNum::from_string("12.34"));
+    /// assert_eq!(BigNum::from_digit_iter(true, (1..=3).rev(), 3), BigNum::from_string("-0.321"));
+    /// assert_eq!(BigNum::from_digit_iter(false, std::iter::empty(), 0), Ok(BigNum::zero()));
+    /// assert!(BigNum::from_digit_iter(false, [1, 10, 3], 0).is_err());
+    /// ```
+    pub fn from_digit_iter<I: IntoIterator<Item = u8>>(negative: bool, most_significant_first: I, decimal_places: u32) -> Result<BigNum, String> {
+        let mut abs = Vec::new();
+        for d in most_significant_first {
+            assert_err!(d < 10, "The digit {} is not valid", d);
+            abs.push(d);
         }
-    }
+        abs.reverse();
 
-    
+        BigNum::new(negative, abs, decimal_places)
+    }
 
-    /// Increase the power of the BigNum to the required value, adding zeroes to match
-    fn with_power(&mut self, n: u32) {
-        if self.power >= n {return;}
 
-        while self.power != n {
-            self.power += 1;
-            self.abs.insert(0, 0);
+    /// Returns a BigNum from the sign and the magnitude of an integer
+    fn from_integer_parts(negative: bool, magnitude: u128) -> BigNum {
+        let mut abs = Vec::new();
+        let mut m = magnitude;
+        while m > 0 {
+            abs.push((m % 10) as u8);
+            m /= 10;
         }
-    }
+        if abs.is_empty() {abs.push(0);}
 
+        let mut res = BigNum {negative, abs, power: 0};
+        res.clean();
+        res
+    }
 
 
 
-    /// Return the opposite of this BigNum  
-    /// Will have no effect on 0 (we prevent -0 from being represented)
+    /// Returns a BigNum from a f64
+    /// The function simply convert the f64 into a string, then calls [Self::from_string]
     /// 
-    /// # Examples
+    /// # Arguments
+    /// 
+    /// * `n` - the number to convert into a BigNum
     /// 
+    /// # Examples
+    ///
     /// ```
     /// use pilosa::BigNum;
     /// 
-    /// let n1 = BigNum::from_string("-245.242").unwrap();
-    /// let n2 = BigNum::zero();
-    /// 
-    /// assert_eq!(n1.opposite(), BigNum::from_string("245.242").unwrap());
-    /// assert_eq!(n2.opposite(), BigNum::zero());
+    /// let number = BigNum::from_f64(134.2452).unwrap();
+    /// let number = BigNum::from_f64(0.0).unwrap();
+    /// let number = BigNum::from_f64(-2.4249252952842).unwrap();
     /// ```
-    pub fn opposite(&self) -> BigNum {
-        // Prevent the creation of -0.
-        if self.is_zero() {
-            return self.clone();
-        }
-
-        BigNum { negative: !self.negative, abs: self.abs.clone(), power: self.power }
+    pub fn from_f64(n: f64) -> Result<BigNum, String> {
+        BigNum::from_string(&n.to_string())
     }
+    /// Returns a BigNum holding the exact value of a f64.
+    /// Unlike [Self::from_f64], which uses the shortest string representing the f64,
+    /// the binary value is converted digit by digit (ex: `0.1` is actually `0.1000000000000000055511151231257827021181583404541015625`).
+    /// Fails if the f64 is NaN or infinite.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the number to convert into a BigNum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_f64_exact(0.5), BigNum::from_string("0.5"));
+    /// assert_eq!(BigNum::from_f64_exact(-3072.0), BigNum::from_string("-3072"));
+    /// assert_eq!(BigNum::from_f64_exact(0.1), BigNum::from_string("0.1000000000000000055511151231257827021181583404541015625"));
+    /// assert!(BigNum::from_f64_exact(f64::NAN).is_err());
+    /// ```
+    pub fn from_f64_exact(n: f64) -> Result<BigNum, String> {
+        assert_err!(n.is_finite(), "Cannot convert a non-finite f64 ({}) into a BigNum", n);
+
+        // n = (-1)^sign * mantissa * 2^exponent
+        let bits = n.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let mut mantissa = bits & ((1 << 52) - 1);
+        let mut exponent = if biased_exponent == 0 {-1074} else {
+            mantissa |= 1 << 52;
+            biased_exponent - 1075
+        };
 
+        if mantissa == 0 {return Ok(BigNum::zero())}
 
+        // reduce the exponent as much as possible to keep the computations small
+        let trailing = mantissa.trailing_zeros();
+        mantissa >>= trailing;
+        exponent += trailing as i32;
 
-    /// Return true if n1 == n2
+        let mut res = BigNum::from(mantissa);
+        if exponent >= 0 {
+            res = &res * &BigNum::bn_pow(&BigNum::from(2), exponent);
+        }
+        else {
+            // mantissa * 2^-k = mantissa * 5^k / 10^k
+            res = &res * &BigNum::bn_pow(&BigNum::from(5), -exponent);
+            res.power += -exponent as u32;
+        }
+
+        res.negative = n.is_sign_negative();
+        res.clean();
+        Ok(res)
+    }
+
+
+    /// Return true if the exact value of the f64 (see [Self::from_f64_exact]) converts back to the very same f64.
+    /// Always false for NaN and infinite values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert!(BigNum::f64_is_exact(0.1));
+    /// assert!(BigNum::f64_is_exact(-2.4249252952842));
+    /// assert!(!BigNum::f64_is_exact(f64::INFINITY));
+    /// ```
+    pub fn f64_is_exact(n: f64) -> bool {
+        match BigNum::from_f64_exact(n) {
+            Ok(b) => b.to_f64().to_bits() == n.to_bits(),
+            Err(_) => false
+        }
+    }
+
+
+
+
+    /// Return the f64 nearest to the BigNum.
+    /// The value is rounded if it can't be represented by a f64, and is infinite if it is too large.
+    /// Use [Self::to_f64_checked] to prevent any rounding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_string("-245.242").unwrap().to_f64(), -245.242);
+    /// assert_eq!(BigNum::from_string("0.1").unwrap().to_f64(), 0.1);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // the standard parser already returns the nearest f64
+        self.to_string().parse().expect("internal error in to_f64")
+    }
+
+
+    /// Return the f64 representing exactly the BigNum.
+    /// Fails if the value would be rounded (ex: `0.1` cannot be represented by a f64) or is too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_string("0.5").unwrap().to_f64_checked(), Ok(0.5));
+    /// assert_eq!(BigNum::from_string("-3072").unwrap().to_f64_checked(), Ok(-3072.0));
+    /// assert!(BigNum::from_string("0.1").unwrap().to_f64_checked().is_err());
+    /// ```
+    pub fn to_f64_checked(&self) -> Result<f64, String> {
+        let res = self.to_f64();
+        assert_err!(res.is_finite(), "{} is too large to be represented by a f64", self);
+        assert_err!(&BigNum::from_f64_exact(res)? == self, "{} cannot be represented exactly by a f64", self);
+
+        Ok(res)
+    }
+
+
+
+
+    /// Convert the BigNum to a usize, truncating its decimal part.
+    /// Negative values return 0 and values too large return `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(-12).to_usize_saturating(), 0);
+    /// assert_eq!(BigNum::from_string("42.99").unwrap().to_usize_saturating(), 42);
+    /// assert_eq!(BigNum::ten_pow(30).to_usize_saturating(), usize::MAX);
+    /// ```
+    pub fn to_usize_saturating(&self) -> usize {
+        if self.negative {return 0}
+
+        let mut res: usize = 0;
+        for d in self.abs.iter().skip(self.power as usize).rev() {
+            match res.checked_mul(10).and_then(|r| r.checked_add(*d as usize)) {
+                Some(r) => res = r,
+                None => return usize::MAX
+            }
+        }
+
+        res
+    }
+
+
+
+
+    /// Convert the BigNum to a signed Qm.n fixed-point value, i.e `self * 2^fraction_bits` rounded to the nearest integer
+    /// (halves are rounded to even, see [RoundingMode::HalfEven]).
+    /// `integer_bits` includes the sign bit, so the result must fit in `integer_bits + fraction_bits` bits
+    /// (ex: Q1.15 values are 16 bits integers, representing numbers in `[-1, 1)`).  
+    /// Returns an error if the result does not fit, or if the total number of bits is 0 or greater than 128.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = |s| BigNum::from_string(s).unwrap();
+    ///
+    /// assert_eq!(n("0.5").to_fixed_point(1, 15), Ok(16384));
+    /// assert_eq!(n("-0.25").to_fixed_point(1, 15), Ok(-8192));
+    /// assert_eq!(n("-1").to_fixed_point(1, 15), Ok(-32768));
+    /// assert!(n("1").to_fixed_point(1, 15).is_err());                  // Q1.15 is in [-1, 1)
+    /// assert_eq!(n("0.1").to_fixed_point(1, 7), Ok(13));               // 12.8 rounded
+    /// assert_eq!(n("2.75").to_fixed_point(8, 1), Ok(6));               // 5.5 rounded to even
+    /// assert_eq!(n("-3.5").to_fixed_point(128, 0), Ok(-4));
+    /// assert!(n("1").to_fixed_point(100, 100).is_err());
+    /// ```
+    pub fn to_fixed_point(&self, integer_bits: u32, fraction_bits: u32) -> Result<i128, String> {
+        let total_bits = integer_bits as u64 + fraction_bits as u64;
+        assert_err!(total_bits > 0 && total_bits <= 128, "Invalid number of bits ({})", total_bits);
+
+        let two = BigNum::from(2);
+        let scaled = BigNum::try_mul(self, &BigNum::try_pow(&two, fraction_bits as i32)?)?.round(0, RoundingMode::HalfEven);
+
+        // the result must be in [-2^(total_bits-1), 2^(total_bits-1))
+        let limit = BigNum::bn_pow(&two, total_bits as i32 - 1);
+        let fits = if scaled.negative {scaled.abs() <= limit} else {scaled < limit};
+        assert_err!(fits, "{} does not fit in Q{}.{}", self, integer_bits, fraction_bits);
+
+        // |scaled| <= 2^127 fits in a u128
+        let magnitude = scaled.abs.iter().rev().fold(0u128, |acc, d| acc * 10 + *d as u128);
+        Ok(if scaled.negative {(magnitude as i128).wrapping_neg()} else {magnitude as i128})
+    }
+
+
+
+    
+
+    /// Modify the given bignums so they have the same power.
+    /// Does not change their values
+    fn same_power(n1: &mut BigNum, n2: &mut BigNum) {
+        if n1.power < n2.power {n1.with_power(n2.power)}
+        else {n2.with_power(n1.power)}
+    }
+
+
+    /// Return copies of both [BigNum] sharing the same power (i.e the same amount of digits after the dot).
+    /// Does not change their values, but the returned copies are not cleaned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.5").unwrap();
+    /// let n2 = BigNum::from_string("-0.0042").unwrap();
+    ///
+    /// let (a1, a2) = BigNum::aligned(&n1, &n2);
+    /// assert_eq!(a1.power(), 4);
+    /// assert_eq!(a2.power(), 4);
+    /// assert!(a1.equals_numeric(&n1));
+    /// assert!(a2.equals_numeric(&n2));
+    /// ```
+    pub fn aligned(a: &BigNum, b: &BigNum) -> (BigNum, BigNum) {
+        let mut a = a.clone();
+        let mut b = b.clone();
+        BigNum::same_power(&mut a, &mut b);
+        (a, b)
+    }
+
+
+
+    /// Return the power both [BigNum] must be aligned to in order to share the same power
+    /// (i.e the larger of their powers). See [BigNum::aligned]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.5").unwrap();
+    /// let n2 = BigNum::from_string("-0.0042").unwrap();
+    ///
+    /// assert_eq!(BigNum::common_scale(&n1, &n2), 4);
+    /// assert_eq!(BigNum::common_scale(&n1, &BigNum::one()), 1);
+    /// ```
+    pub fn common_scale(a: &BigNum, b: &BigNum) -> u32 {std::cmp::max(a.power, b.power)}
+
+
+    /// Return the digits (from least to most significant) of both [BigNum] once aligned to their common scale,
+    /// and this scale. The digits represent the values of the BigNums multiplied by `10^scale`, without their signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.5").unwrap();
+    /// let n2 = BigNum::from_string("-0.0042").unwrap();
+    ///
+    /// let (a, b, scale) = BigNum::rescaled_pair(&n1, &n2);
+    /// assert_eq!(a, vec![0, 0, 0, 5, 2, 1]); // 125000
+    /// assert_eq!(b, vec![2, 4]);             // 42
+    /// assert_eq!(scale, 4);
+    /// assert_eq!(BigNum::new(false, a, scale).unwrap(), n1);
+    /// assert_eq!(BigNum::new(true, b, scale).unwrap(), n2);
+    /// ```
+    pub fn rescaled_pair(a: &BigNum, b: &BigNum) -> (Vec<u8>, Vec<u8>, u32) {
+        let (a, b) = BigNum::aligned(a, b);
+        let scale = a.power;
+        (a.abs, b.abs, scale)
+    }
+
+
+
+
+    /// unclean one of the given BigNum so that both share the same amount of digits.
+    /// Does not change their values
+    fn same_digit_amount(n1: &mut BigNum, n2: &mut BigNum) {
+        core::ub_pad_to(&mut n1.abs, n2.abs.len());
+        core::ub_pad_to(&mut n2.abs, n1.abs.len());
+    }
+
+
+
+    /// Return true if the BigNum has no digit after the dot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert!(BigNum::from_string("-2450").unwrap().is_integer());
+    /// assert!(BigNum::from_string("12.000").unwrap().is_integer());
+    /// assert!(!BigNum::from_string("0.5").unwrap().is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {self.power == 0}
+
+
+
+    /// Return true if the BigNum has at most `places` digits after the dot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert!(BigNum::from_string("1.23").unwrap().fits_in_decimal_places(2));
+    /// assert!(!BigNum::from_string("1.235").unwrap().fits_in_decimal_places(2));
+    /// assert!(BigNum::from_string("1.2000").unwrap().fits_in_decimal_places(1));
+    /// assert!(BigNum::from_string("-2450").unwrap().fits_in_decimal_places(0));
+    /// ```
+    pub fn fits_in_decimal_places(&self, places: u32) -> bool {self.power <= places}
+
+
+
+    /// Return the minimal number of digits after the dot needed to write the BigNum exactly.
+    /// See [BigNum::fits_in_decimal_places]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_string("1.2500").unwrap().required_decimal_places(), 2);
+    /// assert_eq!(BigNum::from_string("-0.001").unwrap().required_decimal_places(), 3);
+    /// assert_eq!(BigNum::from_string("-2450").unwrap().required_decimal_places(), 0);
+    /// assert_eq!(BigNum::zero().required_decimal_places(), 0);
+    /// ```
+    pub fn required_decimal_places(&self) -> u32 {self.power}
+
+
+
+
+    /// Return true if the BigNum is an exact multiple of `10^magnitude`
+    /// (a negative magnitude checks the digits after the dot). 0 is round at any magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert!(BigNum::from(250).is_round_at(1));
+    /// assert!(!BigNum::from(255).is_round_at(1));
+    /// assert!(!BigNum::from(250).is_round_at(2));
+    /// assert!(BigNum::from(-3000).is_round_at(3));
+    /// assert!(BigNum::from_string("1.25").unwrap().is_round_at(-2));
+    /// assert!(!BigNum::from_string("1.25").unwrap().is_round_at(-1));
+    /// assert!(BigNum::zero().is_round_at(5));
+    /// ```
+    pub fn is_round_at(&self, magnitude: i32) -> bool {
+        // number of least significant digits that must be zeroes
+        let count = magnitude as i64 + self.power as i64;
+        if count <= 0 {return true}
+
+        self.abs.iter().take(count as usize).all(|d| *d == 0)
+    }
+
+
+
+
+    /// Return the base 10 logarithm of the BigNum, rounded down (i.e the position of its most significant digit).
+    /// Returns an error if the BigNum is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(999).ilog10(), Ok(2));
+    /// assert_eq!(BigNum::from(1000).ilog10(), Ok(3));
+    /// assert_eq!(BigNum::from_string("0.05").unwrap().ilog10(), Ok(-2));
+    /// assert!(BigNum::zero().ilog10().is_err());
+    /// assert!(BigNum::from(-10).ilog10().is_err());
+    /// ```
+    pub fn ilog10(&self) -> Result<i32, String> {
+        assert_err!(self.is_positive(), "The logarithm is only defined for positive numbers ({})", self);
+
+        // the BigNum is cleaned, so its last digit is its most significant non-zero one
+        let res = self.abs.len() as i64 - 1 - self.power as i64;
+        i32::try_from(res).map_err(|_| format!("The logarithm does not fit in an i32 ({})", res))
+    }
+
+
+
+    /// Return the order of magnitude of the BigNum (i.e `floor(log10(|self|))`), or None if it is zero.
+    /// Unlike [BigNum::ilog10], the sign of the BigNum is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(999).magnitude_bucket(), Some(2));
+    /// assert_eq!(BigNum::from(-999).magnitude_bucket(), Some(2));
+    /// assert_eq!(BigNum::from_string("0.05").unwrap().magnitude_bucket(), Some(-2));
+    /// assert_eq!(BigNum::zero().magnitude_bucket(), None);
+    /// ```
+    pub fn magnitude_bucket(&self) -> Option<i32> {
+        self.abs().ilog10().ok()
+    }
+
+
+
+
+    /// Return two round numbers `(lower, upper)` with `lower <= self <= upper`, to be used as the bounds of a chart axis
+    /// starting at zero and divided into `steps` steps.  
+    /// The heuristic is the following: the step is the smallest number of the form `1`, `2` or `5` times a power of ten
+    /// so that `steps` steps cover `|self|`. The bounds are then `(0, steps * step)` for a positive value,
+    /// and `(-steps * step, 0)` for a negative one, so that `(upper - lower) / step` is exactly `steps`.
+    /// A `steps` of 0 is considered to be 1, and zero uses a step of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = BigNum::from(742);
+    ///
+    /// assert_eq!(n.nice_bounds(1), (BigNum::zero(), BigNum::from(1000)));     // 1 step of 1000
+    /// assert_eq!(n.nice_bounds(5), (BigNum::zero(), BigNum::from(1000)));     // 5 steps of 200
+    /// assert_eq!(n.nice_bounds(4), (BigNum::zero(), BigNum::from(800)));      // 4 steps of 200
+    /// assert_eq!(n.nice_bounds(3), (BigNum::zero(), BigNum::from(1500)));     // 3 steps of 500
+    /// assert_eq!(n.opposite().nice_bounds(1), (BigNum::from(-1000), BigNum::zero()));
+    /// assert_eq!(BigNum::from(1000).nice_bounds(5), (BigNum::zero(), BigNum::from(1000)));
+    /// assert_eq!(BigNum::from_string("0.0372").unwrap().nice_bounds(4), (BigNum::zero(), BigNum::from_string("0.04").unwrap()));
+    /// assert_eq!(BigNum::zero().nice_bounds(10), (BigNum::zero(), BigNum::from(10)));
+    ///
+    /// // the step is always 1, 2 or 5 times a power of ten
+    /// for steps in 1..=12 {
+    ///     let (lower, upper) = n.nice_bounds(steps);
+    ///     let step = BigNum::bn_div(&(&upper - &lower), &BigNum::from(steps)).unwrap();
+    ///     let leading = step.leading_digit().unwrap();
+    ///     assert!([1, 2, 5].contains(&leading) && step.significant_figure_count() == 1);
+    ///     assert!(lower <= n && n <= upper);
+    /// }
+    /// ```
+    pub fn nice_bounds(&self, steps: u32) -> (BigNum, BigNum) {
+        let steps = BigNum::from(std::cmp::max(steps, 1));
+        if self.is_zero() {return (BigNum::zero(), steps)}
+
+        let target = self.abs();
+
+        // start from a power of ten lower than |self| / steps
+        let mut exponent = target.ilog10().expect("self is not zero") - steps.ilog10().expect("steps is positive") - 1;
+        let step = 'search: loop {
+            let power = BigNum::ten_pow(exponent);
+            for m in [1, 2, 5] {
+                let step = &power * &BigNum::from(m);
+                if &step * &steps >= target {break 'search step}
+            }
+            exponent += 1;
+        };
+
+        let span = &step * &steps;
+        if self.is_negative() {(span.opposite(), BigNum::zero())}
+        else {(BigNum::zero(), span)}
+    }
+
+
+
+
+    /// Return the most significant non-zero digit of the BigNum, or None if it is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(-7200).leading_digit(), Some(7));
+    /// assert_eq!(BigNum::from_string("0.0047").unwrap().leading_digit(), Some(4));
+    /// assert_eq!(BigNum::zero().leading_digit(), None);
+    /// ```
+    pub fn leading_digit(&self) -> Option<u8> {
+        if self.is_zero() {return None}
+        // the BigNum is cleaned, so its last digit is not a zero
+        self.abs.last().copied()
+    }
+
+
+
+    /// Return the position of the most significant non-zero digit of the BigNum
+    /// (i.e the power of ten it is multiplied by), or None if it is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(-7200).leading_place(), Some(3));
+    /// assert_eq!(BigNum::from(5).leading_place(), Some(0));
+    /// assert_eq!(BigNum::from_string("0.0047").unwrap().leading_place(), Some(-3));
+    /// assert_eq!(BigNum::zero().leading_place(), None);
+    /// ```
+    pub fn leading_place(&self) -> Option<i32> {
+        if self.is_zero() {return None}
+        Some((self.abs.len() as i64 - 1 - self.power as i64) as i32)
+    }
+
+
+
+
+    /// Return the number of significant figures of the BigNum, i.e the number of digits between its
+    /// most and least significant non-zero digits (included). Zero has no significant figure.  
+    /// **Note:** as BigNums are cleaned, trailing decimal zeroes are not stored (`10.10` is `10.1`),
+    /// so they are never counted. Trailing zeroes of integers are not counted either (`1200` has 2 significant figures).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(1200).significant_figure_count(), 2);
+    /// assert_eq!(BigNum::from(100).significant_figure_count(), 1);
+    /// assert_eq!(BigNum::from_string("0.004500").unwrap().significant_figure_count(), 2);
+    /// assert_eq!(BigNum::from_string("-10.10").unwrap().significant_figure_count(), 3);
+    /// assert_eq!(BigNum::zero().significant_figure_count(), 0);
+    /// ```
+    pub fn significant_figure_count(&self) -> usize {
+        match self.abs.iter().position(|d| *d != 0) {
+            Some(first) => self.abs.len() - first,
+            None => 0
+        }
+    }
+
+
+
+
+    /// Return the integer BigNum obtained by replacing each of its digits by `f(position, digit)`,
+    /// the position of the least significant digit being 0. The sign is kept.  
+    /// Returns an error if the BigNum is not an integer, or if `f` returns a value that is not a digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let double_odd = |i: usize, d: u8| if i % 2 == 1 {d * 2} else {d};
+    ///
+    /// assert_eq!(BigNum::from(1234).map_digits(double_odd), Ok(BigNum::from(2264)));
+    /// assert_eq!(BigNum::from(-1234).map_digits(|_, d| 9 - d), Ok(BigNum::from(-8765)));
+    /// assert!(BigNum::from(1254).map_digits(double_odd).is_err());   // 5 * 2 is not a digit
+    /// assert!(BigNum::from_string("12.5").unwrap().map_digits(double_odd).is_err());
+    /// ```
+    pub fn map_digits<F: Fn(usize, u8) -> u8>(&self, f: F) -> Result<BigNum, String> {
+        assert_err!(self.is_integer(), "{} is not an integer", self);
+
+        let abs = self.abs.iter().enumerate().map(|(i, d)| f(i, *d)).collect();
+        BigNum::new(self.negative, abs, 0)
+    }
+
+
+
+
+    /// Return the sum of the digits of the BigNum modulo 9, which is the value of `|self|` modulo 9 for integers.
+    /// For decimal numbers, it is the value modulo 9 of `|self|` without its dot (ex: `12.5` gives `125 mod 9`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(123456).digital_root_mod9(), 3);
+    /// assert_eq!(BigNum::from(-81).digital_root_mod9(), 0);
+    /// assert_eq!(BigNum::from_string("12.5").unwrap().digital_root_mod9(), 8);
+    /// ```
+    pub fn digital_root_mod9(&self) -> u8 {
+        (self.abs.iter().map(|d| *d as u64).sum::<u64>() % 9) as u8
+    }
+
+
+
+    /// Check cheaply whether `claimed` can be the product of `a` and `b`, by "casting out nines":
+    /// the product of the digit sums of `a` and `b` must be congruent to the digit sum of `claimed` modulo 9
+    /// (see [BigNum::digital_root_mod9]), and the sign of `claimed` must be consistent.  
+    /// A `false` result means that `claimed` is wrong. A `true` result does not guarantee it is right
+    /// (ex: swapped digits are not detected).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let a = BigNum::from_string("1234.5").unwrap();
+    /// let b = BigNum::from(-678);
+    ///
+    /// assert!(BigNum::verify_product(&a, &b, &BigNum::from_string("-836991").unwrap()));
+    /// assert!(!BigNum::verify_product(&a, &b, &BigNum::from_string("-836891").unwrap()));
+    /// assert!(!BigNum::verify_product(&a, &b, &BigNum::from_string("836991").unwrap()));
+    /// ```
+    pub fn verify_product(a: &BigNum, b: &BigNum, claimed: &BigNum) -> bool {
+        let sign_ok = claimed.is_zero() || claimed.negative == (a.negative != b.negative);
+        sign_ok && (a.digital_root_mod9() * b.digital_root_mod9()) % 9 == claimed.digital_root_mod9()
+    }
+
+
+
+    /// Return the integer part of the BigNum (i.e the value truncated toward zero).
+    /// The digits after the dot are simply dropped, without any rounding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_string("-2.99").unwrap().to_integer_trunc(), BigNum::from(-2));
+    /// assert_eq!(BigNum::from_string("1245.5").unwrap().to_integer_trunc(), BigNum::from(1245));
+    /// assert_eq!(BigNum::from_string("-0.5").unwrap().to_integer_trunc(), BigNum::zero());
+    /// assert_eq!(BigNum::from(42).to_integer_trunc(), BigNum::from(42));
+    /// ```
+    pub fn to_integer_trunc(&self) -> BigNum {self.truncated(0)}
+
+
+
+
+    /// Return the BigNum rounded to `places` digits after the dot, using the given [RoundingMode].
+    /// A negative number of places rounds the integer part (ex: `-2` rounds to a multiple of 100)
+    ///
+    /// # Arguments
+    ///
+    /// * `places` - the number of digits after the dot to keep
+    /// * `mode` - the way of rounding the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::{BigNum, RoundingMode};
+    ///
+    /// let n1 = BigNum::from_string("2.345").unwrap();
+    /// let n2 = BigNum::from_string("-2.5").unwrap();
+    /// let n3 = BigNum::from_string("1250").unwrap();
+    ///
+    /// assert_eq!(n1.round(2, RoundingMode::HalfUp), BigNum::from_string("2.35").unwrap());
+    /// assert_eq!(n1.round(2, RoundingMode::HalfEven), BigNum::from_string("2.34").unwrap());
+    /// assert_eq!(n1.round(1, RoundingMode::Down), BigNum::from_string("2.3").unwrap());
+    /// assert_eq!(n1.round(5, RoundingMode::Up), n1);
+    /// assert_eq!(n2.round(0, RoundingMode::HalfUp), BigNum::from(-3));
+    /// assert_eq!(n2.round(0, RoundingMode::HalfDown), BigNum::from(-2));
+    /// assert_eq!(n2.round(0, RoundingMode::Floor), BigNum::from(-3));
+    /// assert_eq!(n2.round(0, RoundingMode::Ceiling), BigNum::from(-2));
+    /// assert_eq!(n3.round(-2, RoundingMode::HalfEven), BigNum::from(1200));
+    /// assert_eq!(n3.round(-4, RoundingMode::Up), BigNum::from(10000));
+    /// ```
+    pub fn round(&self, places: i64, mode: RoundingMode) -> BigNum {
+        if places >= self.power as i64 {return self.clone()}
+
+        // number of digits to remove
+        let k = (self.power as i64 - places) as usize;
+        let mut kept = if k < self.abs.len() {self.abs[k..].to_vec()} else {vec![0]};
+
+        // the most significant dropped digit, and whether the others are zeroes
+        let first_dropped = if k <= self.abs.len() {self.abs[k-1]} else {0};
+        let rest_non_zero = self.abs[..std::cmp::min(k-1, self.abs.len())].iter().any(|d| *d != 0);
+        let non_zero = first_dropped != 0 || rest_non_zero;
+
+        let increment = match mode {
+            RoundingMode::Down => false,
+            RoundingMode::Up => non_zero,
+            RoundingMode::Floor => non_zero && self.negative,
+            RoundingMode::Ceiling => non_zero && !self.negative,
+            RoundingMode::HalfUp => first_dropped >= 5,
+            RoundingMode::HalfDown => first_dropped > 5 || (first_dropped == 5 && rest_non_zero),
+            RoundingMode::HalfEven => first_dropped > 5 || (first_dropped == 5 && (rest_non_zero || kept[0] % 2 == 1)),
+        };
+
+        if increment {kept = core::ub_add(kept, vec![1]);}
+
+        let mut res = if places >= 0 {
+            BigNum { negative: self.negative, abs: kept, power: places as u32 }
+        }
+        else {
+            let mut abs = vec![0; -places as usize];
+            abs.extend(kept);
+            BigNum { negative: self.negative, abs, power: 0 }
+        };
+
+        res.clean();
+        res
+    }
+
+
+
+
+    /// Return true if both BigNums are equal once rounded to `precision` digits after the dot
+    /// (using [RoundingMode::HalfEven]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("0.333333333312").unwrap();
+    /// let n2 = BigNum::from_string("0.3333333333").unwrap();
+    ///
+    /// assert!(n1 != n2);
+    /// assert!(n1.eq_at_precision(&n2, 10));
+    /// assert!(!n1.eq_at_precision(&n2, 11));
+    /// ```
+    pub fn eq_at_precision(&self, other: &BigNum, precision: i64) -> bool {
+        self.round(precision, RoundingMode::HalfEven) == other.round(precision, RoundingMode::HalfEven)
+    }
+
+
+
+
+    /// Return true if both BigNums are equal once their last `ignore_places` digits after the dot are dropped (truncation).  
+    /// Unlike [BigNum::eq_at_precision], the number of digits kept depends on the precision of each value:
+    /// a BigNum with `p` digits after the dot is truncated to `p - ignore_places` digits (or to its integer part).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("1.23456").unwrap();
+    /// let n2 = BigNum::from_string("1.23499").unwrap();
+    ///
+    /// assert!(n1 != n2);
+    /// assert!(n1.eq_ignoring_last(&n2, 2));
+    /// assert!(!n1.eq_ignoring_last(&n2, 1));
+    /// assert!(!n1.eq_ignoring_last(&BigNum::from_string("1.234").unwrap(), 2));  // 1.234 vs 1.2
+    /// assert!(BigNum::from(7).eq_ignoring_last(&BigNum::from_string("7.5").unwrap(), 3));
+    /// ```
+    pub fn eq_ignoring_last(&self, other: &BigNum, ignore_places: u32) -> bool {
+        let drop = |n: &BigNum| n.truncated(n.power.saturating_sub(ignore_places));
+        drop(self) == drop(other)
+    }
+
+
+
+    /// Return the BigNum rounded to `sig` significant digits, using the given [RoundingMode].
+    /// Zero is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `sig` - the number of significant digits to keep (must be at least 1)
+    /// * `mode` - the way of rounding the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::{BigNum, RoundingMode};
+    ///
+    /// let n1 = BigNum::from_string("9.99").unwrap();
+    /// let n2 = BigNum::from_string("-0.012345").unwrap();
+    /// let n3 = BigNum::from_string("123456").unwrap();
+    ///
+    /// assert_eq!(n1.to_precision(2, RoundingMode::HalfUp), BigNum::from(10));
+    /// assert_eq!(n1.to_precision(2, RoundingMode::Down), BigNum::from_string("9.9").unwrap());
+    /// assert_eq!(n2.to_precision(3, RoundingMode::HalfEven), BigNum::from_string("-0.0123").unwrap());
+    /// assert_eq!(n3.to_precision(2, RoundingMode::Ceiling), BigNum::from(130000));
+    /// assert_eq!(n3.to_precision(10, RoundingMode::Up), n3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig` is 0
+    pub fn to_precision(&self, sig: usize, mode: RoundingMode) -> BigNum {
+        assert!(sig > 0, "Cannot round to 0 significant digits");
+        if self.is_zero() {return self.clone()}
+
+        // number of digits before the dot (can be negative for values lower than 0.1)
+        let int_digits = self.abs.len() as i64 - self.power as i64;
+        self.round(sig as i64 - int_digits, mode)
+    }
+
+
+
+
+    /// Return the BigNum rounded to `max_sig` significant digits if it has more than that
+    /// (see [BigNum::significant_figure_count]), or an unchanged copy otherwise.  
+    /// Like the significand of a float, this bounds the size of the values in long computations,
+    /// at the cost of exactness. The magnitude of the value is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::{BigNum, RoundingMode};
+    ///
+    /// let long = BigNum::from_string("-123456789.987654321").unwrap();
+    /// let limited = long.limit_significance(6, RoundingMode::HalfEven);
+    /// assert_eq!(limited, BigNum::from(-123457000));
+    /// assert_eq!(limited.significant_figure_count(), 6);
+    /// assert_eq!(limited.leading_place(), long.leading_place());
+    ///
+    /// let small = BigNum::from_string("0.000123456").unwrap();
+    /// assert_eq!(small.limit_significance(2, RoundingMode::Down), BigNum::from_string("0.00012").unwrap());
+    ///
+    /// // values with few significant digits are not changed
+    /// let short = BigNum::from(1200);
+    /// assert_eq!(short.limit_significance(2, RoundingMode::Up), short);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_sig` is 0
+    pub fn limit_significance(&self, max_sig: usize, mode: RoundingMode) -> BigNum {
+        assert!(max_sig > 0, "Cannot limit a BigNum to 0 significant digits");
+        if self.significant_figure_count() <= max_sig {return self.clone()}
+        self.to_precision(max_sig, mode)
+    }
+
+
+
+    /// Return the string representation of the BigNum rounded to `sig` significant digits.
+    /// See [BigNum::to_precision]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::{BigNum, RoundingMode};
+    ///
+    /// let n = BigNum::from_string("3.14159").unwrap();
+    ///
+    /// assert_eq!(n.to_significant_figures(3, RoundingMode::HalfUp), "3.14");
+    /// assert_eq!(BigNum::from_string("9.99").unwrap().to_significant_figures(2, RoundingMode::HalfUp), "10");
+    /// ```
+    pub fn to_significant_figures(&self, sig: usize, mode: RoundingMode) -> String {
+        self.to_precision(sig, mode).to_string()
+    }
+
+
+
+
+    /// Return the string representation of the BigNum rounded to `places` digits after the dot
+    /// using [RoundingMode::HalfUp], always written with exactly `places` decimals.
+    /// See [BigNum::to_fixed_banker] for banker's rounding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_string("2.5").unwrap().to_fixed(0), "3");
+    /// assert_eq!(BigNum::from_string("-1.005").unwrap().to_fixed(2), "-1.01");
+    /// assert_eq!(BigNum::from(12).to_fixed(3), "12.000");
+    /// ```
+    pub fn to_fixed(&self, places: u32) -> String {self.fixed_string(places, RoundingMode::HalfUp)}
+
+
+
+    /// Return the string representation of the BigNum rounded to `places` digits after the dot
+    /// using banker's rounding ([RoundingMode::HalfEven]), always written with exactly `places` decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = |s| BigNum::from_string(s).unwrap();
+    ///
+    /// assert_eq!(n("2.5").to_fixed_banker(0), "2");
+    /// assert_eq!(n("3.5").to_fixed_banker(0), "4");
+    /// assert_eq!(n("-2.5").to_fixed_banker(0), "-2");
+    /// assert_eq!(n("0.125").to_fixed_banker(2), "0.12");
+    /// assert_eq!(n("0.135").to_fixed_banker(2), "0.14");
+    /// assert_eq!(n("0.1251").to_fixed_banker(2), "0.13");
+    /// assert_eq!(n("1.05").to_fixed_banker(3), "1.050");
+    /// assert_eq!(n("9.995").to_fixed_banker(2), "10.00");
+    /// ```
+    pub fn to_fixed_banker(&self, places: u32) -> String {self.fixed_string(places, RoundingMode::HalfEven)}
+
+
+
+    /// Round the BigNum to `places` digits after the dot, and write it with exactly `places` decimals
+    fn fixed_string(&self, places: u32, mode: RoundingMode) -> String {
+        let rounded = self.round(places as i64, mode);
+        let mut res = rounded.to_string();
+
+        let missing = places - rounded.power;
+        if missing > 0 {
+            if rounded.power == 0 {res.push('.');}
+            res.push_str(&"0".repeat(missing as usize));
+        }
+
+        res
+    }
+
+
+
+
+    /// Return the string representation of an integer BigNum, left-padded with zeroes so that it
+    /// is at least `width` characters long. The sign of negative values is written before the zeroes
+    /// and counts in the width. Returns an error if the BigNum is not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from(42).to_zero_padded(5), Ok("00042".to_string()));
+    /// assert_eq!(BigNum::from(-42).to_zero_padded(5), Ok("-0042".to_string()));
+    /// assert_eq!(BigNum::from(12345).to_zero_padded(5), Ok("12345".to_string()));
+    /// assert_eq!(BigNum::from(1234567).to_zero_padded(5), Ok("1234567".to_string()));
+    /// assert!(BigNum::from_string("4.2").unwrap().to_zero_padded(5).is_err());
+    /// ```
+    pub fn to_zero_padded(&self, width: usize) -> Result<String, String> {
+        assert_err!(self.is_integer(), "{} is not an integer", self);
+
+        let sign = if self.negative {"-"} else {""};
+        let digits: String = self.abs.iter().rev().map(|d| (b'0' + d) as char).collect();
+        let zeroes = width.saturating_sub(sign.len() + digits.len());
+
+        Ok(format!("{}{}{}", sign, "0".repeat(zeroes), digits))
+    }
+
+
+
+
+    /// Return the string representation of the BigNum rounded to `sig_digits` significant digits
+    /// (using [RoundingMode::HalfUp]), with a SI prefix chosen so that the written value is in `[1, 1000)`
+    /// (ex: `1500 -> "1.5k"`). Values outside of the range of the SI prefixes use the largest/smallest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = |s| BigNum::from_string(s).unwrap();
+    ///
+    /// assert_eq!(n("1500").to_si_string(3), "1.5k");
+    /// assert_eq!(n("-2000000").to_si_string(3), "-2M");
+    /// assert_eq!(n("123456789").to_si_string(4), "123.5M");
+    /// assert_eq!(n("0.002").to_si_string(3), "2m");
+    /// assert_eq!(n("0.0000314159").to_si_string(3), "31.4µ");
+    /// assert_eq!(n("999.96").to_si_string(3), "1k");
+    /// assert_eq!(n("42").to_si_string(3), "42");
+    /// assert_eq!(BigNum::zero().to_si_string(3), "0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig_digits` is 0
+    pub fn to_si_string(&self, sig_digits: usize) -> String {
+        // prefixes from 10^-24 to 10^24
+        const PREFIXES: [&str; 17] = ["y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y"];
+
+        let rounded = self.to_precision(sig_digits, RoundingMode::HalfUp);
+        let place = match rounded.leading_place() {
+            Some(place) => place,
+            None => return rounded.to_string()
+        };
+
+        let group = place.div_euclid(3).clamp(-8, 8);
+        let mantissa = if group >= 0 {rounded.bn_tenpow_div(3 * group as isize, false)}
+        else {rounded.bn_tenpow_mul(3 * group.unsigned_abs() as usize, false)};
+
+        format!("{}{}", mantissa, PREFIXES[(group + 8) as usize])
+    }
+
+
+
+    /// Return true if n is 0 (or -0, but it should not happen)
+    pub fn is_zero(&self) -> bool {
+        if self.abs.is_empty() {panic!("Error: BigNum does not have any digit. Please report this error");}
+        self.abs.len() == 1 && self.abs[0] == 0
+    }
+
+
+
+    /// Return false if the BigNum is 0, true otherwise. See [BigNum::from_bool]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert!(BigNum::from_bool(true).to_bool());
+    /// assert!(!BigNum::from_bool(false).to_bool());
+    /// assert!(BigNum::from(-3).to_bool());
+    /// assert!(BigNum::from_string("0.001").unwrap().to_bool());
+    /// ```
+    pub fn to_bool(&self) -> bool {!self.is_zero()}
+
+
+
+    /// Clean the BigNum from any useless information:
+    /// - useless significant zeroes (ex: 010 -> 10)
+    /// - Reduce the power as much possible by removing useless decimal zeroes `(0.10 => 0.1)`
+    /// - Prevent the representation of `-0`
+    fn clean(&mut self) {
+        // no digit represents zero
+        if self.abs.is_empty() {
+            *self = BigNum::zero();
+            return;
+        }
+
+        // decimal zeroes (12.120 => 12.12)
+        // (the last digit is kept so that zero is not left without any digit)
+        let check = |x: &mut BigNum| x.abs.len() > 1 && x.abs.first().unwrap() == &0 && x.power > 0;
+        while check(self) {
+            self.power -= 1;
+            self.abs.remove(0);
+        }
+
+        core::ub_clean(&mut self.abs);
+        
+        // prevent -0 and 0.0
+        if self.is_zero() {
+            self.negative = false;
+            self.power = 0;
+        }
+    }
+
+
+
+    /// Return a cleaned copy of the BigNum (see [BigNum::new] for what cleaning means).
+    /// Non-mutating counterpart of the in-place cleaning, like [core::ub_cleaned] is for [core::ub_clean]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.5").unwrap();
+    /// let n2 = BigNum::from_string("0.0042").unwrap();
+    ///
+    /// // aligned copies are not cleaned: 12.5 is stored as 12.5000
+    /// let (a1, _) = BigNum::aligned(&n1, &n2);
+    /// assert_eq!(a1.power(), 4);
+    ///
+    /// let c = a1.cleaned();
+    /// assert_eq!(c.power(), 1);
+    /// assert_eq!(c.decompose(), n1.decompose());
+    /// ```
+    pub fn cleaned(&self) -> BigNum {
+        let mut res = self.clone();
+        res.clean();
+        res
+    }
+
+
+
+
+    /// Check that the BigNum respects all the invariants of its representation, and return an error describing
+    /// the first violated one otherwise:
+    /// - `abs` holds at least one digit
+    /// - `abs` only holds digits (values lower than 10)
+    /// - no useless significant zeroes (ex: 010)
+    /// - no useless decimal zeroes (ex: 0.10)
+    /// - `-0` is not represented
+    ///
+    /// Every BigNum built by the API is valid, except the uncleaned copies returned by [BigNum::aligned].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.5").unwrap();
+    /// let n2 = BigNum::from_string("-0.0042").unwrap();
+    ///
+    /// assert_eq!(n1.validate(), Ok(()));
+    /// assert_eq!(BigNum::zero().validate(), Ok(()));
+    ///
+    /// let (a1, a2) = BigNum::aligned(&n1, &n2);
+    /// assert_eq!(a1.validate(), Err("abs has useless decimal zeroes (power = 4)".to_string()));
+    /// assert_eq!(a2.validate(), Ok(()));
+    /// assert_eq!(a1.cleaned().validate(), Ok(()));
+    ///
+    /// // one BigNum per violated invariant
+    /// let error = |negative, abs, power| BigNum::new_unchecked(negative, abs, power).validate().unwrap_err();
+    ///
+    /// assert_eq!(error(false, vec![], 0), "abs does not hold any digit");
+    /// assert_eq!(error(false, vec![5, 12], 1), "abs contains a value that is not a Digit (12)");
+    /// assert_eq!(error(true, vec![1, 2, 0], 0), "abs has useless significant zeroes");
+    /// assert_eq!(error(false, vec![0, 0, 5], 2), "abs has useless decimal zeroes (power = 2)");
+    /// assert_eq!(error(true, vec![0], 0), "-0 is represented");
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        assert_err!(!self.abs.is_empty(), "abs does not hold any digit");
+
+        if let Some(d) = self.abs.iter().find(|d| **d >= 10) {
+            return Err(format!("abs contains a value that is not a Digit ({})", d));
+        }
+
+        assert_err!(self.abs.len() == 1 || self.abs[self.abs.len() - 1] != 0, "abs has useless significant zeroes");
+        assert_err!(self.power == 0 || self.abs[0] != 0, "abs has useless decimal zeroes (power = {})", self.power);
+        assert_err!(!(self.negative && self.is_zero()), "-0 is represented");
+
+        Ok(())
+    }
+
+
+
+    /// Increase the power of the BigNum to the required value, adding zeroes to match
+    fn with_power(&mut self, n: u32) {
+        if self.power >= n {return;}
+
+        while self.power != n {
+            self.power += 1;
+            self.abs.insert(0, 0);
+        }
+    }
+
+
+
+
+    /// Return the absolute value of this BigNum
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// assert_eq!(BigNum::from_string("-245.242").unwrap().abs(), BigNum::from_string("245.242").unwrap());
+    /// assert_eq!(BigNum::from_string("0.5").unwrap().abs(), BigNum::from_string("0.5").unwrap());
+    /// ```
+    pub fn abs(&self) -> BigNum {
+        BigNum { negative: false, abs: self.abs.clone(), power: self.power }
+    }
+
+
+
+    /// Return the opposite of this BigNum  
+    /// Will have no effect on 0 (we prevent -0 from being represented)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("-245.242").unwrap();
+    /// let n2 = BigNum::zero();
+    /// 
+    /// assert_eq!(n1.opposite(), BigNum::from_string("245.242").unwrap());
+    /// assert_eq!(n2.opposite(), BigNum::zero());
+    /// ```
+    pub fn opposite(&self) -> BigNum {
+        // Prevent the creation of -0.
+        if self.is_zero() {
+            return self.clone();
+        }
+
+        BigNum { negative: !self.negative, abs: self.abs.clone(), power: self.power }
+    }
+
+
+
+
+    /// Return the opposite of this BigNum if `condition` is true, or a copy of it otherwise.
+    /// Like [BigNum::opposite], will have no effect on 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("-2.5").unwrap();
+    /// let n2 = BigNum::from_string("2.5").unwrap();
+    /// let n3 = BigNum::zero();
+    ///
+    /// assert_eq!(n1.negate_if(true), n2);
+    /// assert_eq!(n1.negate_if(false), n1);
+    /// assert_eq!(n2.negate_if(true), n1);
+    /// assert_eq!(n2.negate_if(false), n2);
+    /// assert_eq!(n3.negate_if(true), BigNum::zero());
+    /// assert!(!n3.negate_if(true).is_negative());
+    /// assert_eq!(n3.negate_if(false), BigNum::zero());
+    /// ```
+    pub fn negate_if(&self, condition: bool) -> BigNum {
+        if condition {self.opposite()} else {self.clone()}
+    }
+
+
+
+    /// Return true if n1 == n2
     /// Will not work if both [BigNum] are not cleaned
     fn are_equal(n1: &BigNum, n2: &BigNum) -> bool {
         n1.negative == n2.negative && n1.abs == n2.abs && n1.power == n2.power
     }
 
 
-    /// Return true if n1 < n2
-    fn is_lower(n1: &BigNum, n2: &BigNum) -> bool {
-        // easy cmp of signs
-        if n1.negative && !n2.negative {return true}
-        else if !n1.negative && n2.negative {return false}
+    /// Return true if both [BigNum] have the same numeric value.
+    /// Unlike `==`, both values are cleaned before being compared, so the
+    /// result does not depend on how they were built (ex: `1.5` and `1.50` are always equal).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n2 = BigNum::from_string("1.5").unwrap();
+    /// let n3 = BigNum::from_string("-1.5").unwrap();
+    ///
+    /// // aligned returns uncleaned copies: n1 is stored as 1.500
+    /// let (n1, _) = BigNum::aligned(&n2, &BigNum::from_string("0.001").unwrap());
+    ///
+    /// assert!(n1 != n2);
+    /// assert!(n1.equals_numeric(&n2));
+    /// assert!(n2.equals_numeric(&n1));
+    /// assert!(!n2.equals_numeric(&n3));
+    /// ```
+    pub fn equals_numeric(&self, other: &BigNum) -> bool {
+        let mut n1 = self.clone();
+        let mut n2 = other.clone();
+        n1.clean();
+        n2.clean();
+
+        BigNum::are_equal(&n1, &n2)
+    }
+
+
+
+    /// Return true if both BigNums are exactly `step` apart (i.e `|self - other| == step`),
+    /// whatever their signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let step = BigNum::from_string("0.5").unwrap();
+    ///
+    /// assert!(BigNum::from(1).is_adjacent(&BigNum::from_string("1.5").unwrap(), &step));
+    /// assert!(BigNum::from_string("0.25").unwrap().is_adjacent(&BigNum::from_string("-0.25").unwrap(), &step));
+    /// assert!(!BigNum::from(1).is_adjacent(&BigNum::from(2), &step));
+    /// ```
+    pub fn is_adjacent(&self, other: &BigNum, step: &BigNum) -> bool {
+        self.abs_diff(other) == *step
+    }
+
+
+
+    /// Return the absolute difference between both BigNums, i.e `|self - other|`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("1.25").unwrap();
+    /// let n2 = BigNum::from(-2);
+    ///
+    /// assert_eq!(n1.abs_diff(&n2), BigNum::from_string("3.25").unwrap());
+    /// assert_eq!(n2.abs_diff(&n1), BigNum::from_string("3.25").unwrap());
+    /// assert_eq!(n1.abs_diff(&n1), BigNum::zero());
+    /// ```
+    pub fn abs_diff(&self, other: &BigNum) -> BigNum {
+        (self - other).abs()
+    }
+
+
+
+    /// Return a copy of whichever of `a` and `b` is the closest to `target`. In case of a tie, `a` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let target = BigNum::from_string("1.5").unwrap();
+    /// let n = |s| BigNum::from_string(s).unwrap();
+    ///
+    /// assert_eq!(BigNum::closest_to(&target, &n("1.4"), &n("1.7")), n("1.4"));
+    /// assert_eq!(BigNum::closest_to(&target, &n("-1.5"), &n("3")), n("3"));
+    /// assert_eq!(BigNum::closest_to(&target, &n("1"), &n("2")), n("1"));     // tie
+    /// assert_eq!(BigNum::closest_to(&target, &n("2"), &n("1")), n("2"));     // tie
+    /// ```
+    pub fn closest_to(target: &BigNum, a: &BigNum, b: &BigNum) -> BigNum {
+        if target.abs_diff(b) < target.abs_diff(a) {b.clone()} else {a.clone()}
+    }
+
+
+
+
+    /// Return true if the BigNum is in the half-open interval `[lo, hi)`, i.e `lo <= self < hi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let lo = BigNum::from_string("-1.5").unwrap();
+    /// let hi = BigNum::from(2);
+    ///
+    /// assert!(lo.in_range(&lo, &hi));                                    // lo is included
+    /// assert!(!hi.in_range(&lo, &hi));                                   // hi is excluded
+    /// assert!(BigNum::zero().in_range(&lo, &hi));
+    /// assert!(!BigNum::from_string("-1.51").unwrap().in_range(&lo, &hi));
+    /// assert!(!BigNum::from(3).in_range(&lo, &hi));
+    /// assert!(!BigNum::zero().in_range(&hi, &hi));                       // empty interval
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`
+    pub fn in_range(&self, lo: &BigNum, hi: &BigNum) -> bool {
+        assert!(lo <= hi, "Invalid range: {} > {}", lo, hi);
+        lo <= self && self < hi
+    }
+
+
+
+
+    /// Return the midpoint `(a + b) / 2`, rounded to `scale` digits after the dot (using [RoundingMode::HalfEven]).  
+    /// Useful for bisection searches, as the precision of the midpoints does not grow at each step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::snapped_midpoint(&BigNum::from(1), &BigNum::from(2), 0), BigNum::from(2));
+    /// assert_eq!(BigNum::snapped_midpoint(&BigNum::from(1), &BigNum::from(2), 1), BigNum::from_string("1.5").unwrap());
+    ///
+    /// // bisection search of sqrt(2), with 3 digits after the dot
+    /// let two = BigNum::from(2);
+    /// let step = BigNum::from_string("0.001").unwrap();
+    /// let (mut lo, mut hi) = (BigNum::one(), two.clone());
+    /// let mut iterations = 0;
+    ///
+    /// while &hi - &lo > step {
+    ///     let mid = BigNum::snapped_midpoint(&lo, &hi, 3);
+    ///     assert!(mid.power() <= 3);
+    ///     if &mid * &mid < two {lo = mid} else {hi = mid}
+    ///     iterations += 1;
+    /// }
+    ///
+    /// assert!(iterations < 20);
+    /// assert_eq!(lo, BigNum::from_string("1.414").unwrap());
+    /// ```
+    pub fn snapped_midpoint(a: &BigNum, b: &BigNum, scale: u32) -> BigNum {
+        // (a + b) / 2 = (a + b) * 5 / 10, which is exact
+        let half_sum = &(a + b) * &BigNum::from(5);
+        half_sum.bn_tenpow_div(1, false).round(scale as i64, RoundingMode::HalfEven)
+    }
+
+
+    /// Return true if n1 < n2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = |s| BigNum::from_string(s).unwrap();
+    ///
+    /// // values lower than 0.1 have a negative number of whole digits
+    /// assert!(n("0.005") < n("0.05"));
+    /// assert!(n("-0.05") < n("-0.005"));
+    /// assert!(n("0.05") < n("12"));
+    ///
+    /// // zero has less whole digits than any other value
+    /// assert!(BigNum::zero() < n("0.001"));
+    /// assert!(n("-0.001") < BigNum::zero());
+    /// assert!(!(n("0.001") < BigNum::zero()));
+    /// ```
+    fn is_lower(n1: &BigNum, n2: &BigNum) -> bool {
+        // easy cmp of signs
+        if n1.negative && !n2.negative {return true}
+        else if !n1.negative && n2.negative {return false}
+
+        // if both are negative, calculations may vary
+        let neg = n1.negative && n2.negative;
+
+        // easy cmp with the number of whole digits (negative if |n| < 0.1)
+        // zero is lower than any other positive number, whatever its number of digits
+        let whole_digits = |n: &BigNum| if n.is_zero() {isize::MIN} else {n.abs.len() as isize - n.power as isize};
+        if whole_digits(n1) != whole_digits(n2) {
+            if neg {return whole_digits(n1) > whole_digits(n2)}
+            else {return whole_digits(n1) < whole_digits(n2)}
+        }
+
+        // Same amount of digits before the '.', so we can compare each digit one by one
+        let min_len = std::cmp::min(n1.abs.len(), n2.abs.len());
+        let len_n1 = n1.abs.len();
+        let len_n2 = n2.abs.len();
+
+        for i in 0..min_len {
+            let d1 = &n1.abs[len_n1 - i - 1];
+            let d2 = &n2.abs[len_n2 - i - 1];
+
+            if neg {
+                if d1 < d2 {return false}
+                if d1 > d2 {return true}
+            }
+            else {
+                if d1 < d2 {return true}
+                if d1 > d2 {return false}
+            }
+        }
+
+        // at this point we reach the end of at least one BigNum
+        if neg {
+            len_n2 == min_len && len_n1 != min_len
+        }
+        else {
+            len_n1 == min_len && len_n2 != min_len
+        }
+    }
+
+
+
+
+    /// Return true if n1 > n2
+    fn is_greater(n1: &BigNum, n2: &BigNum) -> bool {
+        !BigNum::are_equal(n1, n2) && !BigNum::is_lower(n1, n2)
+    }
+
+
+    /// If n is a power of ten, return x so that n = 10^x
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("100").unwrap();
+    /// let n2 = BigNum::from_string("101").unwrap();
+    /// let n3 = BigNum::from_string("1").unwrap();
+    /// 
+    /// assert_eq!(n1.is_power_of_ten(), Some(2));
+    /// assert_eq!(n2.is_power_of_ten(), None);
+    /// assert_eq!(n3.is_power_of_ten(), Some(0));
+    /// ```
+    pub fn is_power_of_ten(&self) -> Option<isize> {
+        let abs_power = core::is_power_of_ten(&self.abs)?;
+        Some(abs_power as isize - self.power as isize)
+    }
+
+
+
+    /// Return the [BigNum] multiplied by 10^power  
+    /// This is quicker than using the basic multiplication algorithm
+    /// as it's only a matter of adding or removing zeroes in the inner representation.
+    /// 
+    /// # Arguments
+    /// * `power` - A number so that self is multiplied by 10^power
+    /// * `pow_negative` - true = the power of ten is negative (ex: self * -10^3)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("123").unwrap();
+    /// let n2 = BigNum::from_string("0.02423").unwrap();
+    /// 
+    /// assert_eq!(n1.bn_tenpow_mul(2, false), BigNum::from_string("12300").unwrap());
+    /// assert_eq!(n2.bn_tenpow_mul(3, true), BigNum::from_string("-24.23").unwrap());
+    /// ```
+    pub fn bn_tenpow_mul(&self, power: usize, pow_negative: bool) -> BigNum {
+        // result values
+        let mut final_power = self.power;
+        let mut abs = self.abs.clone();
+
+        // i lied
+        let mut power = power;
+        while power > 0 {
+            if final_power > 0 {final_power -= 1;}
+            else {abs.insert(0, 0);}
+            power -= 1;
+        }
+
+        let mut res = BigNum {negative: self.negative != pow_negative, abs: abs, power: final_power};
+        res.clean();
+        res
+    }
+
+
+
+    /// Return the [BigNum] divided by 10^power  
+    /// This is -way- quicker than using the basic division algorithm
+    /// as it's only a matter of adding or removing zeroes in the inner representation.
+    /// 
+    /// # Arguments
+    /// * `power` - A number so that [BigNum] is divided by 10^power
+    /// * `pow_negative` - true = the power of ten is negative (ex: self * -10^3)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("123").unwrap();
+    /// let n2 = BigNum::from_string("0.02423").unwrap();
+    /// let n3 = BigNum::from_string("-2498.244").unwrap();
+    /// 
+    /// assert_eq!(n1.bn_tenpow_div(2, false), BigNum::from_string("1.23").unwrap());
+    /// assert_eq!(n2.bn_tenpow_div(3, true), BigNum::from_string("-0.00002423").unwrap());
+    /// assert_eq!(n3.bn_tenpow_div(0, false), BigNum::from_string("-2498.244").unwrap());
+    /// ```
+    pub fn bn_tenpow_div(&self, power: isize, pow_negative: bool) -> BigNum {
+        // very simple function as we only need to increase
+        // the n.power by power
+        if power == 0 && !pow_negative {return self.clone()}
+
+        let mut res = BigNum {negative: self.negative != pow_negative, abs: self.abs.clone(), power: self.power + power as u32};
+        res.clean();
+        res
+    }
+
+
+
+
+    /// Return the multiplication of 2 [BigNum]  
+    /// Panics if the power of the result overflows (see [BigNum::try_mul])
+    pub fn bn_mul(n1: &BigNum, n2: &BigNum) -> BigNum {
+        BigNum::try_mul(n1, n2).unwrap()
+    }
+
+
+
+
+    /// Return the multiplication of 2 [BigNum].  
+    /// Fails if the result would have more than `u32::MAX` digits after the dot.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("-12.5").unwrap();
+    /// let n2 = BigNum::from_string("0.04").unwrap();
+    /// let n3 = BigNum::new(false, vec![1], 3_000_000_000).unwrap(); // 10^-3000000000
+    /// 
+    /// assert_eq!(BigNum::try_mul(&n1, &n2), Ok(BigNum::from_string("-0.5").unwrap()));
+    /// assert!(BigNum::try_mul(&n3, &n3).is_err());
+    /// 
+    /// let big = BigNum::from_string("98765432109876543210.123456789").unwrap();
+    /// assert!(BigNum::verify_product(&big, &n1, &BigNum::try_mul(&big, &n1).unwrap()));
+    /// ```
+    pub fn try_mul(n1: &BigNum, n2: &BigNum) -> Result<BigNum, String> {
+        // Maybe we could check if n2 is a power of ten to use bn_tenpow_mu; here
+        // i don't know if it is worth it
+
+        let pow = n1.power.checked_add(n2.power);
+        assert_err!(pow.is_some(), "The power of the multiplication overflows ({} + {})", n1.power, n2.power);
+
+        let sign = n1.negative != n2.negative;
+        let abs = core::ub_mul(&n1.abs, &n2.abs);
+
+        let mut res = BigNum { negative: sign, abs, power: pow.unwrap() };
+        
+        res.clean();
+        Ok(res)
+    }
+
+
+
+
+    /// Return the "euclidian" quotient and remainder of num / denom.  
+    /// More precisely, it returns `q` and `r` so that `num = denom * q + r` with `r < denom`    
+    /// **Note:** division by zero is not allowed as, if `denom = 0`, we have `num = 0 * q + r`. In that case `q` does not have a defined value.
+    /// 
+    /// # Arguments
+    /// * `num` - the numerator of the division, >= 0
+    /// * `denum` - the denominator of the division, > 0
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("1332").unwrap();
+    /// let n2 = BigNum::from_string("12").unwrap();
+    /// let n3 = BigNum::zero();
+    /// 
+    /// assert_eq!(BigNum::euclidian(&n1, &n2), Ok((BigNum::from_string("111").unwrap(), BigNum::from_string("0").unwrap()))); 
+    /// assert_eq!(BigNum::euclidian(&n2, &n1), Ok((BigNum::zero(), BigNum::from_string("12").unwrap()))); 
+    /// assert!(BigNum::euclidian(&n1, &n3).is_err());
+    /// ```
+    pub fn euclidian(num: &BigNum, denom: &BigNum) -> Result<(BigNum, BigNum), String> {
+        assert_err!(!denom.is_zero(), "Division by zero");
+        assert_err!(!num.is_negative(), "The numerator cannot be negative");
+        assert_err!(!denom.is_negative(), "The denominator cannot be negative");
+
+        let mut remainder = num.clone();
+        let mut quotient = BigNum::zero();
+
+        while &remainder >= &denom {
+            remainder = &remainder - denom;
+            quotient += BigNum::one();
+        }
+
+        Ok((quotient, remainder))
+    }
+
+
+
+
+
+
+    /// Return the sum of 2 BigNums of the same sign.
+    fn inner_add(n1: &BigNum, n2: &BigNum) -> BigNum {
+        if n1.negative != n2.negative {panic!("inner_add can only add BigNums of the same sign")}
+
+        let mut n1 = n1.clone();
+        let mut n2 = n2.clone();
+        BigNum::same_power(&mut n1, &mut n2);
+
+        // Create the new value
+        let sum = core::ub_add(n1.abs, n2.abs);
+        let mut res = BigNum {
+            negative: n1.negative, // n2.negative would work too (as n1.negative == n2.negative)
+            abs: sum,   
+            power: n1.power        // same
+        };
+
+        res.clean();
+        res
+    }
+
+
+
+
+
+
+    /// Return the diff of 2 positive BigNums.
+    /// panics if n1 < n2
+    fn inner_sub(n1: &BigNum, n2: &BigNum) -> BigNum {
+        if n1.negative || n2.negative {panic!("inner_sub can only substract positive BigNums")}
+        if n1 < n2 {panic!("inner_sub requires n1 > n2")}
+
+        let mut n1 = n1.clone();
+        let mut n2 = n2.clone();
+
+        BigNum::same_power(&mut n1, &mut n2);
+        BigNum::same_digit_amount(&mut n1, &mut n2);
+
+        let mut res = BigNum::new(false, core::ub_sub(n1.abs, n2.abs).expect("internal error in inner_sub"), n1.power).unwrap();
+
+        res.clean();
+        res
+    }
+
+
+
+    
+
+
+    /// Add two [BigNum] together
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("53643.368359").unwrap();
+    /// let n2 = BigNum::from_string("-22398247.24982").unwrap();
+    /// let n3 = BigNum::zero();
+    /// let n4 = BigNum::from_string("-32089").unwrap();
+    /// let n5 = BigNum::from_string("209").unwrap();
+    /// 
+    /// assert_eq!(BigNum::bn_add(&n1, &n1), BigNum::from_string("107286.736718").unwrap());
+    /// assert_eq!(BigNum::bn_add(&n1, &n2), BigNum::from_string("-22344603.881461").unwrap());
+    /// assert_eq!(BigNum::bn_add(&n2, &n3), BigNum::from_string("-22398247.24982").unwrap());
+    /// assert_eq!(BigNum::bn_add(&n1, &n1), BigNum::from_string("107286.736718").unwrap());
+    /// assert_eq!(BigNum::bn_add(&n4, &n5), BigNum::from_string("-31880").unwrap());
+    /// ```
+    pub fn bn_add(n1: &BigNum, n2: &BigNum) -> BigNum {
+        // Transform the addition in order to use inner_add (addition of same sign)
+        // or inner_sub (substraction of positive BigNums)
+        match (n1.negative, n2.negative) {
+            (false, false) => { // x + y
+                BigNum::inner_add(n1, n2)
+            },
+            (true, true) => { // -x + -y
+                BigNum::inner_add(n1, n2)
+            },
+            (true, false) => { // -x + y <=> y - x
+                n2 - &n1.opposite()
+            },
+            (false, true) => { // x + -y <=> x - y
+                n1 - &n2.opposite()
+            },
+        }
+    }
+
+
+
+
+
+
+    /// Substract one [BigNum] to another
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("53643.368359").unwrap();
+    /// let n2 = BigNum::from_string("24872398247.24982").unwrap();
+    /// let n3 = BigNum::zero();
+    /// let n4 = BigNum::from_string("-32089").unwrap();
+    /// let n5 = BigNum::from_string("209").unwrap();
+    /// 
+    /// assert_eq!(BigNum::bn_sub(&n1, &n2), BigNum::from_string("-24872344603.881461").unwrap());
+    /// assert_eq!(BigNum::bn_sub(&n2, &n3), BigNum::from_string("24872398247.24982").unwrap());
+    /// assert_eq!(BigNum::bn_sub(&n1, &n1), BigNum::zero());
+    /// assert_eq!(BigNum::bn_sub(&n4, &n5), BigNum::from_string("-32298").unwrap());
+    /// 
+    /// // x - x = 0, whatever x is
+    /// for x in [&n1, &n2, &n3, &n4, &n5] {
+    ///     assert_eq!(BigNum::bn_sub(x, x), BigNum::zero());
+    /// }
+    /// ```
+    pub fn bn_sub(n1: &BigNum, n2: &BigNum) -> BigNum {
+        // quick path, which does not require any computation
+        if n1 == n2 {return BigNum::zero()}
+
+        let mut res = match (n1.negative, n2.negative) {
+            (false, false) => {
+                if n1 < n2 {BigNum::inner_sub(n2, n1).opposite()} // require n1 > n2 :    (x-y) <=> -(y-x)
+                else {BigNum::inner_sub(n1, n2)}
+            },
+            (true, true) => {  // -x - -y <=> y - x
+                &n2.opposite() - &n1.opposite()
+            },
+            (true, false) => { // -x - y <=> -x + -y
+                n1 + &n2.opposite()
+            },
+            (false, true) => { // x - -y <=> x + y
+                n1 + &n2.opposite()
+            },
+        };
+
+        res.clean();
+        res
+    }
+
+
+
+    /// Return the sum of all the given [BigNum] (0 for an empty slice).  
+    /// The values are aligned once and their digits accumulated directly,
+    /// which is faster than adding them one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let items = vec![
+    ///     BigNum::from_string("12.5").unwrap(),
+    ///     BigNum::from_string("-0.25").unwrap(),
+    ///     BigNum::from_string("100").unwrap(),
+    ///     BigNum::from_string("-112.25").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(BigNum::bn_sum(&items), BigNum::zero());
+    /// assert_eq!(BigNum::bn_sum(&items[..3]), BigNum::from_string("112.25").unwrap());
+    /// assert_eq!(BigNum::bn_sum(&[]), BigNum::zero());
+    /// ```
+    pub fn bn_sum(items: &[BigNum]) -> BigNum {
+        let power = items.iter().map(|n| n.power).max().unwrap_or(0);
+
+        // sums of the positive and negative values, aligned on the same power
+        let mut positive = vec![0];
+        let mut negative = vec![0];
+
+        for n in items {
+            let mut abs = vec![0; (power - n.power) as usize];
+            abs.extend(&n.abs);
+
+            if n.negative {negative = core::ub_add(negative, abs);}
+            else {positive = core::ub_add(positive, abs);}
+        }
+
+        let mut res = match core::ub_checked_sub(&positive, &negative) {
+            Some(abs) => BigNum { negative: false, abs, power },
+            None => BigNum { negative: true, abs: core::ub_checked_sub(&negative, &positive).unwrap(), power }
+        };
+
+        res.clean();
+        res
+    }
+
+
+
+    /// Return the arithmetic mean of the given [BigNum], computed with `precision` decimal digits.  
+    /// Returns an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let items: Vec<BigNum> = (1..=4).map(BigNum::from).collect();
+    /// let single = vec![BigNum::from_string("-3.75").unwrap()];
+    ///
+    /// assert_eq!(BigNum::mean(&items, 10), Ok(BigNum::from_string("2.5").unwrap()));
+    /// assert_eq!(BigNum::mean(&single, 10), Ok(single[0].clone()));
+    /// assert_eq!(BigNum::mean(&items[..3], 3), Ok(BigNum::from(2)));
+    /// assert!(BigNum::mean(&[], 10).is_err());
+    /// ```
+    pub fn mean(items: &[BigNum], precision: i64) -> Result<BigNum, String> {
+        assert_err!(!items.is_empty(), "Cannot compute the mean of an empty slice");
+        BigNum::bn_div_prec(&BigNum::bn_sum(items), &BigNum::from(items.len()), precision)
+    }
+
+
+
+
+    /// Return the dot product of `a` and `b`, i.e `a[0] * b[0] + a[1] * b[1] + ...`  
+    /// The products are accumulated all at once (see [BigNum::bn_sum]).
+    /// Returns an error if the slices do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let a = [1, 2, 3].map(BigNum::from);
+    /// let b = [4, 5, 6].map(BigNum::from);
+    /// let c = ["0.5", "-0.25"].map(|n| BigNum::from_string(n).unwrap());
+    ///
+    /// assert_eq!(BigNum::dot(&a, &b), Ok(BigNum::from(32)));
+    /// assert_eq!(BigNum::dot(&a[..2], &c), Ok(BigNum::zero()));
+    /// assert_eq!(BigNum::dot(&[], &[]), Ok(BigNum::zero()));
+    /// assert!(BigNum::dot(&a, &c).is_err());
+    /// ```
+    pub fn dot(a: &[BigNum], b: &[BigNum]) -> Result<BigNum, String> {
+        assert_err!(a.len() == b.len(), "The slices must have the same length ({} != {})", a.len(), b.len());
+
+        let products = std::iter::zip(a, b)
+            .map(|(x, y)| BigNum::try_mul(x, y))
+            .collect::<Result<Vec<BigNum>, String>>()?;
+
+        Ok(BigNum::bn_sum(&products))
+    }
+
+
+
+
+    /// Return the running sums of the items: `[items[0], items[0] + items[1], ...]`  
+    /// An empty slice returns an empty Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let items = ["1.5", "-2", "0.25", "10"].map(|n| BigNum::from_string(n).unwrap());
+    /// let sums = BigNum::cumulative_sum(&items);
+    ///
+    /// assert_eq!(sums, ["1.5", "-0.5", "-0.25", "9.75"].map(|n| BigNum::from_string(n).unwrap()));
+    /// assert_eq!(sums.last(), Some(&BigNum::bn_sum(&items)));
+    /// assert!(BigNum::cumulative_sum(&[]).is_empty());
+    /// ```
+    pub fn cumulative_sum(items: &[BigNum]) -> Vec<BigNum> {
+        let mut acc = BigNum::zero();
+
+        items.iter().map(|n| {
+            acc += n;
+            acc.clone()
+        }).collect()
+    }
+
+
+
+
+    /// Return the first differences of the values: `[values[1] - values[0], values[2] - values[1], ...]`  
+    /// An empty or single-element slice returns an empty Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let values: Vec<BigNum> = (0..5).map(|i| BigNum::from_string("1.5").unwrap() + BigNum::from_string("0.25").unwrap() * BigNum::from(i)).collect();
+    ///
+    /// assert_eq!(BigNum::finite_differences(&values), vec![BigNum::from_string("0.25").unwrap(); 4]);
+    /// assert_eq!(BigNum::finite_differences(&values[..1]), vec![]);
+    /// assert_eq!(BigNum::finite_differences(&[]), vec![]);
+    /// ```
+    pub fn finite_differences(values: &[BigNum]) -> Vec<BigNum> {
+        values.windows(2).map(|w| &w[1] - &w[0]).collect()
+    }
+
+
+
+    /// Return the forward difference table of the values: the first row holds the values,
+    /// and each following row holds the first differences of the previous one (see [BigNum::finite_differences]),
+    /// until a row has only one element. An empty slice returns an empty table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// // squares: the second differences are constant
+    /// let values = [1, 4, 9, 16].map(BigNum::from);
+    /// let table = BigNum::forward_difference_table(&values);
+    ///
+    /// assert_eq!(table, vec![
+    ///     values.to_vec(),
+    ///     [3, 5, 7].map(BigNum::from).to_vec(),
+    ///     [2, 2].map(BigNum::from).to_vec(),
+    ///     vec![BigNum::zero()],
+    /// ]);
+    /// assert!(BigNum::forward_difference_table(&[]).is_empty());
+    /// ```
+    pub fn forward_difference_table(values: &[BigNum]) -> Vec<Vec<BigNum>> {
+        if values.is_empty() {return vec![]}
+
+        let mut table = vec![values.to_vec()];
+        while table[table.len() - 1].len() > 1 {
+            let next = BigNum::finite_differences(&table[table.len() - 1]);
+            table.push(next);
+        }
+
+        table
+    }
+
+
+
+
+    /// Return the product of all the given [BigNum] (1 for an empty slice).  
+    /// The factors are multiplied following a balanced tree, so that the operands of each multiplication
+    /// have similar sizes. With the `rayon` feature, the sub-trees are multiplied in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let factors: Vec<BigNum> = (1..=1000u32).map(|i| BigNum::from(i % 7 + 1)).collect();
+    /// let serial = factors.iter().fold(BigNum::one(), |acc, f| &acc * f);
+    ///
+    /// assert_eq!(BigNum::par_product(&factors), serial);
+    /// assert_eq!(BigNum::par_product(&[BigNum::from_string("0.5").unwrap(), BigNum::from(-3)]), BigNum::from_string("-1.5").unwrap());
+    /// assert_eq!(BigNum::par_product(&[]), BigNum::one());
+    /// ```
+    pub fn par_product(items: &[BigNum]) -> BigNum {
+        // small slices are not worth splitting
+        if items.len() <= PRODUCT_TREE_LEAF_LEN {
+            return items.iter().fold(BigNum::one(), |acc, n| &acc * n);
+        }
+
+        let (left, right) = items.split_at(items.len() / 2);
+        let (left, right) = BigNum::join_products(left, right);
+        &left * &right
+    }
+
+
+    /// Compute the products of both slices (in parallel)
+    #[cfg(feature = "rayon")]
+    fn join_products(left: &[BigNum], right: &[BigNum]) -> (BigNum, BigNum) {
+        rayon::join(|| BigNum::par_product(left), || BigNum::par_product(right))
+    }
+
+    /// Compute the products of both slices
+    #[cfg(not(feature = "rayon"))]
+    fn join_products(left: &[BigNum], right: &[BigNum]) -> (BigNum, BigNum) {
+        (BigNum::par_product(left), BigNum::par_product(right))
+    }
+
+
+
+
+    /// Return a uniformly distributed random value in `[lo, hi)`, with at most `decimal_places` digits after the dot.  
+    /// A random integer is drawn in the range scaled by `10^decimal_places`, then divided back.
+    /// Return an error if `lo >= hi`, or if no such value exists in the range.  
+    /// Only available with the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let lo = BigNum::from_string("-1.5").unwrap();
+    /// let hi = BigNum::from_string("123456789012345678901234567890.25").unwrap();
+    ///
+    /// for _ in 0..200 {
+    ///     let n = BigNum::random_in_range(&mut rng, &lo, &hi, 3).unwrap();
+    ///     assert!(lo <= n && n < hi);
+    ///     assert!(n.fits_in_decimal_places(3));
+    /// }
+    ///
+    /// // only 0.2 and 0.3 are possible
+    /// let lo = BigNum::from_string("0.15").unwrap();
+    /// let hi = BigNum::from_string("0.3001").unwrap();
+    /// for _ in 0..50 {
+    ///     let n = BigNum::random_in_range(&mut rng, &lo, &hi, 1).unwrap().to_string();
+    ///     assert!(n == "0.2" || n == "0.3");
+    /// }
+    ///
+    /// assert!(BigNum::random_in_range(&mut rng, &hi, &lo, 1).is_err());
+    /// assert!(BigNum::random_in_range(&mut rng, &lo, &lo, 1).is_err());
+    /// assert!(BigNum::random_in_range(&mut rng, &lo, &hi, 0).is_err()); // no integer in [0.15, 0.3001)
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_in_range(rng: &mut impl rand::Rng, lo: &BigNum, hi: &BigNum, decimal_places: u32) -> Result<BigNum, String> {
+        if lo >= hi {return Err(format!("The lower bound ({}) must be less than the upper bound ({})", lo, hi));}
+
+        let places = decimal_places as usize;
+        let lo_scaled = lo.bn_tenpow_mul(places, false).round(0, RoundingMode::Ceiling);
+        let hi_scaled = hi.bn_tenpow_mul(places, false).round(0, RoundingMode::Ceiling);
+        let width = &hi_scaled - &lo_scaled;
+        assert_err!(!width.is_zero(), "No value with {} decimal places in [{}, {})", decimal_places, lo, hi);
+
+        // rejection sampling: draw as many digits as width, the most significant one
+        // being at most the leading digit of width, so that at least half of the draws are accepted
+        let leading = width.abs[width.abs.len() - 1];
+        let offset = loop {
+            let mut digits: Vec<u8> = (1..width.abs.len()).map(|_| rng.gen_range(0..10)).collect();
+            digits.push(rng.gen_range(0..=leading));
+
+            let candidate = BigNum::new(false, digits, 0)?;
+            if candidate < width {break candidate;}
+        };
+
+        Ok((&lo_scaled + &offset).bn_tenpow_div(places as isize, false))
+    }
+
+
+
+
+
+
+
+
+    /// Divide one [BigNum] by another.  
+    /// The result will have a maximum precision of FLOAT_PRECISION digits after the dot. If the result is not perfect (ex: non-decimal values), the result
+    /// will be NOT be rounded, so the actual precision will be +- 10^(-FLOAT_PRECISION)
+    /// 
+    /// # Arguments
+    /// * `n1` - a [BigNum]
+    /// * `n2` - a [BigNum]. Must not be zero or the operation results in an error.
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("1224.235").unwrap();
+    /// let n2 = BigNum::from_string("12").unwrap();
+    /// let n3 = BigNum::from_string("0").unwrap();
+    /// 
+    /// assert_eq!(BigNum::bn_div(&n1, &n2), Ok(BigNum::from_string("102.019583333333333").unwrap())); // considering FLOAT_PRECISION = 15
+    /// assert!(BigNum::bn_div(&n1, &n3).is_err());
+    ///
+    /// // divisions by powers of ten and of values already more precise than FLOAT_PRECISION are exact
+    /// let n4 = BigNum::from_string("1.123456789012345").unwrap();
+    /// assert_eq!(&n4 / &BigNum::from(10), BigNum::from_string("0.1123456789012345").unwrap());
+    /// assert_eq!(BigNum::bn_pow(&BigNum::from(10), -17), BigNum::from_string("0.00000000000000001").unwrap());
+    /// assert_eq!(BigNum::from_string("0.00000000000000000003").unwrap() / BigNum::from(3), BigNum::from_string("0.00000000000000000001").unwrap());
+    /// ```
+    pub fn bn_div(n1: &BigNum, n2: &BigNum) -> Result<BigNum, String> {
+        BigNum::div_with_min_precision(n1, n2, FLOAT_PRECISION)
+    }
+
+
+
+
+    /// Divide one [BigNum] by another, with a maximum of `precision` digits after the dot.  
+    /// Like [BigNum::bn_div], the result is NOT rounded but truncated, so the actual precision will be +- 10^(-precision)
+    /// 
+    /// # Arguments
+    /// * `n1` - a [BigNum]
+    /// * `n2` - a [BigNum]. Must not be zero or the operation results in an error.
+    /// * `precision` - the maximum number of digits after the dot, >= 0
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("1224.235").unwrap();
+    /// let n2 = BigNum::from_string("12").unwrap();
+    /// let n3 = BigNum::from_string("0.01").unwrap();
+    /// 
+    /// assert_eq!(BigNum::bn_div_prec(&n1, &n2, 4), Ok(BigNum::from_string("102.0195").unwrap()));
+    /// assert_eq!(BigNum::bn_div_prec(&n1, &n2, 0), Ok(BigNum::from_string("102").unwrap()));
+    /// assert_eq!(BigNum::bn_div_prec(&n1, &n3, 2), Ok(BigNum::from_string("122423.5").unwrap()));
+    /// assert_eq!(BigNum::bn_div_prec(&n3, &n1, 2), Ok(BigNum::zero()));
+    /// assert!(BigNum::bn_div_prec(&n1, &BigNum::zero(), 2).is_err());
+    /// assert!(BigNum::bn_div_prec(&n1, &n2, -1).is_err());
+    /// ```
+    pub fn bn_div_prec(n1: &BigNum, n2: &BigNum, precision: i64) -> Result<BigNum, String> {
+        assert_err!(precision >= 0, "The precision cannot be negative");
+        Ok(BigNum::div_with_min_precision(n1, n2, precision)?.truncated(precision as u32))
+    }
+
+
+
+
+    /// Divide one [BigNum] by another, computing at least `precision` digits after the dot.  
+    /// The result is exact when n2 is a power of ten, and keeps all its digits when
+    /// `n1.power - n2.power` already exceeds `precision`.
+    fn div_with_min_precision(n1: &BigNum, n2: &BigNum, precision: i64) -> Result<BigNum, String> {
+        // prevent zero division
+        assert_err!(!n2.is_zero(), "Division by zero");
+
+        if n1.is_zero() {return Ok(BigNum::zero())}
+
+        // checking if n2 is a power of ten
+        // really worth it (compared to bn_mul) as it could prevent precision lost
+        // (the normal algorithm would return 10 / 100 = 0.0999999999)
+        if let Some(p) = n2.is_power_of_ten() {
+            let res = if p >= 0 {n1.bn_tenpow_div(p, n2.is_negative())}
+            else {n1.bn_tenpow_mul(-p as usize, n2.is_negative())};
+            return Ok(res);
+        }
+
+        let sign = n1.negative != n2.negative;
+        let pow = n1.power as i64 - n2.power as i64; // pow can be negative. If so it will be modified after the division
+
+
+        let mut n1 = n1.clone();
+        let n2 = n2.clone();
+
+
+
+        // increase the power of n1 so that n1.power - n2.power >= precision
+        let delta = precision - pow;
+        if delta > 0 {n1.with_power(n1.power + delta as u32);}
+
+
+        let (quotient, _) = if n2.abs.len() == 1 {
+            let (q, r) = core::ub_shortdiv(n1.abs, n2.abs[0]).expect("n2 was not clean when passed to bn_div, resulting in a division by 0"); // n2.abs[0] should not be 0
+            (q, vec![r])
+        } else if n1.abs.len() < n2.abs.len() {
+            (vec![0], n1.abs) // n1.abs < n2.abs, so the quotient is 0
+        } else {
+            core::ub_div(&n1.abs, &n2.abs)?
+        };
+        
+        debug_assert!(n1.power >= n2.power, "resulting power is negative");
+
+        // return the cleaned result
+        let mut res = BigNum { negative: sign, abs: quotient, power: n1.power - n2.power};
+        res.clean();
+
+        Ok(res)
+    }
+
+
+
+
+    /// Return the [BigNum] with a maximum of `places` digits after the dot.
+    /// The removed digits are simply dropped (truncation toward zero)
+    fn truncated(&self, places: u32) -> BigNum {
+        if self.power <= places {return self.clone()}
+
+        let k = (self.power - places) as usize;
+        if k >= self.abs.len() {return BigNum::zero()}
+
+        let mut res = BigNum { negative: self.negative, abs: self.abs[k..].to_vec(), power: places };
+        res.clean();
+        res
+    }
+
+
+
+
+    /// Return `numer / denom` with a maximum of `precision` digits after the dot.  
+    /// Both `numer` and `denom` must be integers. The result is truncated like in [BigNum::bn_div_prec].
+    /// 
+    /// # Arguments
+    /// * `numer` - the numerator, an integer [BigNum]
+    /// * `denom` - the denominator, a non-zero integer [BigNum]
+    /// * `precision` - the maximum number of digits after the dot, >= 0
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_i32(1).unwrap();
+    /// let n2 = BigNum::from_i32(3).unwrap();
+    /// let n3 = BigNum::from_i32(10).unwrap();
+    /// let n4 = BigNum::from_i32(4).unwrap();
+    /// 
+    /// assert_eq!(BigNum::from_ratio(&n1, &n2, 5), Ok(BigNum::from_string("0.33333").unwrap()));
+    /// assert_eq!(BigNum::from_ratio(&n3, &n4, 2), Ok(BigNum::from_string("2.5").unwrap()));
+    /// assert!(BigNum::from_ratio(&n1, &BigNum::zero(), 5).is_err());
+    /// assert!(BigNum::from_ratio(&BigNum::from_string("1.5").unwrap(), &n2, 5).is_err());
+    /// ```
+    pub fn from_ratio(numer: &BigNum, denom: &BigNum, precision: i64) -> Result<BigNum, String> {
+        assert_err!(numer.is_integer(), "The numerator must be an integer");
+        assert_err!(denom.is_integer(), "The denominator must be an integer");
+
+        BigNum::bn_div_prec(numer, denom, precision)
+    }
+
+
+
+
+    /// Parse a fraction like `"3/4"` and return its value with a maximum of `precision` digits after the dot
+    /// (exact if the division terminates before that). The numerator and denominator are parsed
+    /// with [BigNum::from_string], and the division is truncated like in [BigNum::bn_div_prec].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::from_fraction_string("3/4", 10), BigNum::from_string("0.75"));
+    /// assert_eq!(BigNum::from_fraction_string("1/3", 5), BigNum::from_string("0.33333"));
+    /// assert_eq!(BigNum::from_fraction_string("-1.5 / 2", 5), BigNum::from_string("-0.75"));
+    ///
+    /// assert!(BigNum::from_fraction_string("1/0", 5).is_err());
+    /// assert!(BigNum::from_fraction_string("1/2/3", 5).is_err());
+    /// assert!(BigNum::from_fraction_string("12", 5).is_err());
+    /// ```
+    pub fn from_fraction_string(s: &str, precision: i64) -> Result<BigNum, String> {
+        let parts: Vec<&str> = s.split('/').collect();
+        assert_err!(parts.len() == 2, "A fraction must contain exactly one '/' ({:?})", s);
+
+        let numer = BigNum::from_string(parts[0].trim())?;
+        let denom = BigNum::from_string(parts[1].trim())?;
+        BigNum::bn_div_prec(&numer, &denom, precision)
+    }
+
+
+
+
+    /// Return the quotient and the remainder of the division of 2 positive integer [BigNum]
+    /// (faster than [BigNum::euclidian] as it uses [core::ub_divmod])
+    fn int_div_rem(num: &BigNum, denom: &BigNum) -> Result<(BigNum, BigNum), String> {
+        debug_assert!(num.is_integer() && denom.is_integer(), "int_div_rem requires integers");
+        debug_assert!(!num.negative && !denom.negative, "int_div_rem requires positive BigNums");
+
+        let (q, r) = core::ub_divmod(&num.abs, &denom.abs)?;
+        Ok((BigNum::new(false, q, 0)?, BigNum::new(false, r, 0)?))
+    }
+
+
+
+
+    /// Return the quotient of `a / b` truncated toward zero (an integer), and the remainder `r = a - b * q`,
+    /// which has the sign of `a`. Works with decimal values and does not lose any precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from(-7);
+    /// let n2 = BigNum::from(2);
+    /// let n3 = BigNum::from_string("7.5").unwrap();
+    /// let n4 = BigNum::from_string("0.2").unwrap();
+    ///
+    /// assert_eq!(BigNum::signed_div_rem(&n1, &n2), Ok((BigNum::from(-3), BigNum::from(-1))));
+    /// assert_eq!(BigNum::signed_div_rem(&n3, &n2), Ok((BigNum::from(3), BigNum::from_string("1.5").unwrap())));
+    /// assert_eq!(BigNum::signed_div_rem(&n3, &n4.opposite()), Ok((BigNum::from(-37), BigNum::from_string("0.1").unwrap())));
+    /// assert!(BigNum::signed_div_rem(&n1, &BigNum::zero()).is_err());
+    /// ```
+    pub fn signed_div_rem(a: &BigNum, b: &BigNum) -> Result<(BigNum, BigNum), String> {
+        assert_err!(!b.is_zero(), "Division by zero");
+
+        // both magnitudes are integers once aligned to the same power
+        let (u, v, scale) = BigNum::rescaled_pair(a, b);
+        let (q, r) = core::ub_divmod(&core::ub_cleaned(u), &core::ub_cleaned(v))?;
+
+        Ok((BigNum::new(a.negative != b.negative, q, 0)?, BigNum::new(a.negative, r, scale)?))
+    }
+
+
+
+    /// Return the remainder of the truncated division `a / b` (see [BigNum::signed_div_rem]), which has the sign of `a`.  
+    /// This is the remainder computed by the `%` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// assert_eq!(BigNum::bn_rem(&BigNum::from(-7), &BigNum::from(3)), Ok(BigNum::from(-1)));
+    /// assert_eq!(BigNum::from(7) % BigNum::from(-3), BigNum::one());
+    /// assert_eq!(BigNum::from_string("5.5").unwrap() % BigNum::from(2), BigNum::from_string("1.5").unwrap());
+    /// assert!(BigNum::bn_rem(&BigNum::one(), &BigNum::zero()).is_err());
+    /// ```
+    pub fn bn_rem(a: &BigNum, b: &BigNum) -> Result<BigNum, String> {
+        let (_, r) = BigNum::signed_div_rem(a, b)?;
+        Ok(r)
+    }
+
+
+
+    /// Return the floor of `a / b` (i.e the greatest integer lower or equal to the quotient).
+    /// Differs from the truncated quotient of [BigNum::signed_div_rem] when the result is negative and not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let seven = BigNum::from(7);
+    /// let two = BigNum::from(2);
+    ///
+    /// assert_eq!(BigNum::floor_div(&seven, &two), Ok(BigNum::from(3)));
+    /// assert_eq!(BigNum::floor_div(&seven.opposite(), &two), Ok(BigNum::from(-4)));
+    /// assert_eq!(BigNum::floor_div(&seven, &two.opposite()), Ok(BigNum::from(-4)));
+    /// assert_eq!(BigNum::floor_div(&seven.opposite(), &two.opposite()), Ok(BigNum::from(3)));
+    /// assert_eq!(BigNum::floor_div(&BigNum::from(-8), &two), Ok(BigNum::from(-4)));
+    /// assert!(BigNum::floor_div(&seven, &BigNum::zero()).is_err());
+    /// ```
+    pub fn floor_div(a: &BigNum, b: &BigNum) -> Result<BigNum, String> {
+        let (q, r) = BigNum::signed_div_rem(a, b)?;
+
+        if !r.is_zero() && a.negative != b.negative {Ok(&q - &BigNum::one())}
+        else {Ok(q)}
+    }
+
+
+
+    /// Return the remainder of the floor division `a / b` (see [BigNum::floor_div]), i.e `a - b * floor_div(a, b)`.  
+    /// The result has the sign of `b`, so it is always non-negative for a positive divisor.
+    /// Note that the `%` operator uses the truncated division instead (see [BigNum::bn_rem]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from(-7);
+    /// let n2 = BigNum::from(3);
+    ///
+    /// assert_eq!(BigNum::mod_floor(&n1, &n2), Ok(BigNum::from(2)));
+    /// assert_eq!(&n1 % &n2, BigNum::from(-1));
+    /// assert_eq!(BigNum::mod_floor(&n2, &n1), Ok(BigNum::from(-4)));
+    /// assert_eq!(BigNum::mod_floor(&BigNum::from(-6), &n2), Ok(BigNum::zero()));
+    /// assert!(BigNum::mod_floor(&n1, &BigNum::zero()).is_err());
+    /// ```
+    pub fn mod_floor(a: &BigNum, b: &BigNum) -> Result<BigNum, String> {
+        let (_, r) = BigNum::signed_div_rem(a, b)?;
+
+        if !r.is_zero() && a.negative != b.negative {Ok(&r + b)}
+        else {Ok(r)}
+    }
+
+
+
+
+    /// Split a duration into the number of whole `unit_seconds` it contains and the leftover,
+    /// i.e `self = count * unit_seconds + leftover` (see [BigNum::signed_div_rem]).
+    /// Returns an error if `unit_seconds` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let minute = BigNum::from(60);
+    ///
+    /// assert_eq!(BigNum::from(3661).as_duration_parts(&minute), Ok((BigNum::from(61), BigNum::one())));
+    /// assert_eq!(BigNum::from_string("90.5").unwrap().as_duration_parts(&minute), Ok((BigNum::one(), BigNum::from_string("30.5").unwrap())));
+    /// assert!(BigNum::from(3661).as_duration_parts(&BigNum::zero()).is_err());
+    /// assert!(BigNum::from(3661).as_duration_parts(&minute.opposite()).is_err());
+    /// ```
+    pub fn as_duration_parts(&self, unit_seconds: &BigNum) -> Result<(BigNum, BigNum), String> {
+        assert_err!(unit_seconds.is_positive(), "The unit must be positive ({})", unit_seconds);
+        BigNum::signed_div_rem(self, unit_seconds)
+    }
+
+
+
+
+
+    /// Break the BigNum down into mixed units (ex: hours, minutes, seconds), given the sizes of the units
+    /// in descending order. Return how many of each unit fit, followed by the final remainder
+    /// (smaller than the last unit), so that `self = sum(counts[i] * unit_sizes[i]) + remainder`.  
+    /// Each step uses [BigNum::signed_div_rem], so for a negative value every part is negative.
+    /// Returns an error if a unit is not a positive integer, or if the units are not strictly descending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let units = [3600, 60, 1].map(BigNum::from);
+    /// let parts = |s| BigNum::from_string(s).unwrap().decompose_units(&units).unwrap();
+    ///
+    /// assert_eq!(parts("3725"), [1, 2, 5, 0].map(BigNum::from));
+    /// assert_eq!(parts("3725.5"), [BigNum::from(1), BigNum::from(2), BigNum::from(5), BigNum::from_string("0.5").unwrap()]);
+    /// assert_eq!(parts("-61"), [0, -1, -1, 0].map(BigNum::from));
+    ///
+    /// // feet and inches, in inches
+    /// let feet_inches = [12, 1].map(BigNum::from);
+    /// assert_eq!(BigNum::from(75).decompose_units(&feet_inches), Ok([6, 3, 0].map(BigNum::from).to_vec()));
+    ///
+    /// assert!(BigNum::from(10).decompose_units(&[BigNum::from(0)]).is_err());
+    /// assert!(BigNum::from(10).decompose_units(&[BigNum::from_string("1.5").unwrap()]).is_err());
+    /// assert!(BigNum::from(10).decompose_units(&[BigNum::from(1), BigNum::from(60)]).is_err());
+    /// ```
+    pub fn decompose_units(&self, unit_sizes: &[BigNum]) -> Result<Vec<BigNum>, String> {
+        for (i, unit) in unit_sizes.iter().enumerate() {
+            assert_err!(unit.is_positive() && unit.is_integer(), "The unit sizes must be positive integers ({})", unit);
+            assert_err!(i == 0 || unit < &unit_sizes[i - 1], "The unit sizes must be strictly descending ({} then {})", unit_sizes[i - 1], unit);
+        }
+
+        let mut parts = Vec::with_capacity(unit_sizes.len() + 1);
+        let mut remainder = self.clone();
+        for unit in unit_sizes {
+            let (count, rest) = BigNum::signed_div_rem(&remainder, unit)?;
+            parts.push(count);
+            remainder = rest;
+        }
+
+        parts.push(remainder);
+        Ok(parts)
+    }
+
+
+
+
+    /// Return the fraction `(numerator, denominator)` closest to the [BigNum], with `denominator <= max_denominator`.  
+    /// The fraction is computed using the continued fraction expansion of the number (the result is always reduced).
+    /// As the [BigNum] is a decimal number, it is returned as is (reduced) if its denominator is small enough.
+    /// 
+    /// **Note:** the well-known approximation `22/7` of `3.14159` is only returned for denominators lower than 57:
+    /// with a maximum denominator of 100, `311/99` is closer (`|311/99 - 3.14159| ≈ 0.00018` against `≈ 0.00127` for `22/7`).
+    /// 
+    /// # Arguments
+    /// * `max_denominator` - an integer [BigNum] >= 1
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let pi = BigNum::from_string("3.14159").unwrap();
+    /// let n = BigNum::from_string("-0.75").unwrap();
+    /// let ten = BigNum::from_i32(10).unwrap();
+    /// let hundred = BigNum::from_i32(100).unwrap();
+    /// 
+    /// assert_eq!(pi.best_rational(&ten), Ok((BigNum::from_i32(22).unwrap(), BigNum::from_i32(7).unwrap())));
+    /// assert_eq!(pi.best_rational(&BigNum::from(56)), Ok((BigNum::from_i32(22).unwrap(), BigNum::from_i32(7).unwrap())));
+    /// assert_eq!(pi.best_rational(&BigNum::from(57)), Ok((BigNum::from_i32(179).unwrap(), BigNum::from_i32(57).unwrap())));
+    /// assert_eq!(pi.best_rational(&hundred), Ok((BigNum::from_i32(311).unwrap(), BigNum::from_i32(99).unwrap())));
+    /// assert_eq!(n.best_rational(&hundred), Ok((BigNum::from_i32(-3).unwrap(), BigNum::from_i32(4).unwrap())));
+    /// assert!(pi.best_rational(&BigNum::zero()).is_err());
+    /// ```
+    pub fn best_rational(&self, max_denominator: &BigNum) -> Result<(BigNum, BigNum), String> {
+        assert_err!(max_denominator.is_integer(), "The maximum denominator must be an integer");
+        assert_err!(!max_denominator.is_negative() && !max_denominator.is_zero(), "The maximum denominator must be >= 1");
+
+        // |self| = p / q
+        let p = BigNum { negative: false, abs: self.abs.clone(), power: 0 };
+        let q = BigNum::one().bn_tenpow_mul(self.power as usize, false);
+
+        // (p0 / q0) and (p1 / q1) are the last 2 convergents
+        let (mut p0, mut q0, mut p1, mut q1) = (BigNum::zero(), BigNum::one(), BigNum::one(), BigNum::zero());
+        let (mut n, mut d) = (p.clone(), q.clone());
+
+        while !d.is_zero() {
+            let (a, r) = BigNum::int_div_rem(&n, &d)?;
+            let q2 = &q0 + &(&a * &q1);
+            if &q2 > max_denominator {break}
+
+            let p2 = &p0 + &(&a * &p1);
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            (n, d) = (d, r);
+        }
+
+        let (num, denom) = if d.is_zero() {
+            // the expansion terminated: p1 / q1 is exactly |self|
+            (p1, q1)
+        }
+        else {
+            // the best approximation is either the last convergent or the largest semiconvergent below the bound
+            let (k, _) = BigNum::int_div_rem(&(max_denominator - &q0), &q1)?;
+            let p_semi = &p0 + &(&k * &p1);
+            let q_semi = &q0 + &(&k * &q1);
+
+            // |p1/q1 - p/q| <= |p_semi/q_semi - p/q|  <=>  |p1*q - p*q1| * q_semi <= |p_semi*q - p*q_semi| * q1
+            let dist_conv = &(&(&p1 * &q) - &(&p * &q1)).abs() * &q_semi;
+            let dist_semi = &(&(&p_semi * &q) - &(&p * &q_semi)).abs() * &q1;
+
+            if dist_conv <= dist_semi {(p1, q1)} else {(p_semi, q_semi)}
+        };
+
+        Ok((if self.negative {num.opposite()} else {num}, denom))
+    }
+
+
+
+
+    /// Return the first `max_terms` coefficients `[a0; a1, a2, ...]` of the continued fraction expansion of the BigNum,
+    /// i.e `self = a0 + 1 / (a1 + 1 / (a2 + ...))`. The expansion stops early once it is exact (which always happens,
+    /// as a BigNum is a rational number). `a0` is the floor of the BigNum, the other coefficients are positive.
+    ///
+    /// The coefficients are computed exactly by repeatedly taking the integer part and the reciprocal of the fractional part,
+    /// using the Euclidean algorithm on the numerator and denominator of the BigNum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let terms = |s, max| BigNum::from_string(s).unwrap().continued_fraction(max).unwrap();
+    /// let ints = |v: &[i32]| v.iter().map(|i| BigNum::from(*i)).collect::<Vec<BigNum>>();
+    ///
+    /// assert_eq!(terms("3.245", 10), ints(&[3, 4, 12, 4]));       // 649/200
+    /// assert_eq!(terms("3.245", 2), ints(&[3, 4]));
+    /// assert_eq!(terms("3.14159", 3), ints(&[3, 7, 15]));
+    /// assert_eq!(terms("-0.75", 10), ints(&[-1, 4]));            // -1 + 1/4
+    /// assert_eq!(terms("42", 10), ints(&[42]));
+    /// assert_eq!(terms("42", 0), ints(&[]));
+    /// ```
+    pub fn continued_fraction(&self, max_terms: usize) -> Result<Vec<BigNum>, String> {
+        // self = num / den
+        let mut num = BigNum::new(self.negative, self.abs.clone(), 0)?;
+        let mut den = BigNum::one().bn_tenpow_mul(self.power as usize, false);
+
+        let mut res = Vec::new();
+        while res.len() < max_terms && !den.is_zero() {
+            let a = BigNum::floor_div(&num, &den)?;
+            let r = &num - &(&a * &den);
+
+            res.push(a);
+            num = std::mem::replace(&mut den, r);
+        }
+
+        Ok(res)
+    }
+
+
+
+
+
+    /// Return `(a * b) mod modulus`.
+    /// 
+    /// # Arguments
+    /// * `a` & `b` - non-negative integer [BigNum]
+    /// * `modulus` - a positive integer [BigNum]
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from(7);
+    /// let n2 = BigNum::from(8);
+    /// let n3 = BigNum::from(5);
+    /// let n4 = BigNum::from_string("12345678901234567890").unwrap();
+    /// let n5 = BigNum::from_string("98765432109876543210").unwrap();
+    /// let n6 = BigNum::from(1_000_000_007);
+    /// 
+    /// assert_eq!(BigNum::bn_modmul(&n1, &n2, &n3), Ok(BigNum::one()));
+    /// assert_eq!(BigNum::bn_modmul(&n4, &n5, &n6), Ok(BigNum::from(774706380)));
+    /// assert!(BigNum::bn_modmul(&n1, &n2, &BigNum::zero()).is_err());
+    /// assert!(BigNum::bn_modmul(&BigNum::from_string("1.5").unwrap(), &n2, &n3).is_err());
+    /// ```
+    pub fn bn_modmul(a: &BigNum, b: &BigNum, modulus: &BigNum) -> Result<BigNum, String> {
+        assert_err!(a.is_integer() && b.is_integer(), "The operands must be integers");
+        assert_err!(!a.is_negative() && !b.is_negative(), "The operands cannot be negative");
+        assert_err!(modulus.is_integer(), "The modulus must be an integer");
+        assert_err!(!modulus.is_negative() && !modulus.is_zero(), "The modulus must be positive");
+
+        let (_, res) = BigNum::int_div_rem(&BigNum::bn_mul(a, b), modulus)?;
+        Ok(res)
+    }
+
+
+
+
+    /// Return the modular inverse of `a`, i.e the value `x` in `[0, modulus)` so that `(a * x) mod modulus = 1`.  
+    /// Based on the extended Euclidean algorithm.
+    ///
+    /// # Arguments
+    /// * `a` - an integer [BigNum], coprime with `modulus`
+    /// * `modulus` - an integer [BigNum] > 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let m = BigNum::from(11);
+    ///
+    /// assert_eq!(BigNum::mod_inverse(&BigNum::from(3), &m), Ok(BigNum::from(4)));
+    /// assert_eq!(BigNum::mod_inverse(&BigNum::from(-3), &m), Ok(BigNum::from(7)));
+    /// assert_eq!(BigNum::mod_inverse(&BigNum::from(17), &BigNum::from(3120)), Ok(BigNum::from(2753)));
+    /// assert!(BigNum::mod_inverse(&BigNum::from(4), &BigNum::from(10)).is_err());
+    /// assert!(BigNum::mod_inverse(&BigNum::from_string("1.5").unwrap(), &m).is_err());
+    /// assert!(BigNum::mod_inverse(&BigNum::from(3), &BigNum::one()).is_err());
+    /// ```
+    pub fn mod_inverse(a: &BigNum, modulus: &BigNum) -> Result<BigNum, String> {
+        assert_err!(a.is_integer() && modulus.is_integer(), "The operands must be integers");
+        assert_err!(modulus.is_positive() && *modulus != BigNum::one(), "The modulus must be greater than 1");
+
+        // a * x + modulus * y = g, so a * x = g (mod modulus)
+        let (g, x, _) = BigNum::ext_gcd(a, modulus)?;
+        assert_err!(g == BigNum::one(), "{} is not invertible modulo {}", a, modulus);
+
+        BigNum::mod_floor(&x, modulus)
+    }
+
+
+
+    /// Return `(g, x, y)` where `g` is the greatest common divisor of `a` and `b` (always non-negative),
+    /// and `x` and `y` are Bézout coefficients: `a * x + b * y = g`.  
+    /// Based on the extended Euclidean algorithm.
+    ///
+    /// # Arguments
+    /// * `a` & `b` - integer [BigNum] (can be negative)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let check = |a: i32, b: i32, g: i32| {
+    ///     let (a, b) = (BigNum::from(a), BigNum::from(b));
+    ///     let (res, x, y) = BigNum::ext_gcd(&a, &b).unwrap();
+    ///     assert_eq!(res, BigNum::from(g));
+    ///     assert_eq!(BigNum::bn_add(&BigNum::bn_mul(&a, &x), &BigNum::bn_mul(&b, &y)), res);
+    /// };
+    ///
+    /// check(240, 46, 2);
+    /// check(-240, 46, 2);
+    /// check(240, -46, 2);
+    /// check(17, 3120, 1);
+    /// check(0, 5, 5);
+    /// check(0, 0, 0);
+    ///
+    /// assert_eq!(BigNum::ext_gcd(&BigNum::from(240), &BigNum::from(46)), Ok((BigNum::from(2), BigNum::from(-9), BigNum::from(47))));
+    /// assert!(BigNum::ext_gcd(&BigNum::from_string("2.5").unwrap(), &BigNum::one()).is_err());
+    /// ```
+    pub fn ext_gcd(a: &BigNum, b: &BigNum) -> Result<(BigNum, BigNum, BigNum), String> {
+        assert_err!(a.is_integer() && b.is_integer(), "The operands must be integers");
+
+        // invariants: old_r = |a| * old_x + |b| * old_y and r = |a| * x + |b| * y
+        let (mut old_r, mut r) = (a.abs(), b.abs());
+        let (mut old_x, mut x) = (BigNum::one(), BigNum::zero());
+        let (mut old_y, mut y) = (BigNum::zero(), BigNum::one());
+
+        while !r.is_zero() {
+            let (q, rem) = BigNum::int_div_rem(&old_r, &r)?;
+            let next_x = &old_x - &(&q * &x);
+            let next_y = &old_y - &(&q * &y);
+
+            old_r = std::mem::replace(&mut r, rem);
+            old_x = std::mem::replace(&mut x, next_x);
+            old_y = std::mem::replace(&mut y, next_y);
+        }
+
+        Ok((old_r, old_x.negate_if(a.negative), old_y.negate_if(b.negative)))
+    }
+
+
+
+    /// Return the remainder of the division of an integer [BigNum] by `m`, without performing a full division.  
+    /// The digits are folded from the most significant one (`r = (10 * r + d) mod m`),
+    /// which is much faster than [BigNum::euclidian] for small moduli.
+    /// The sign of the BigNum is ignored (i.e the remainder of its absolute value is returned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from(123456);
+    /// let n2 = BigNum::from_string("12345678901234567890").unwrap();
+    ///
+    /// assert_eq!(n1.rem_u64(7), Ok(4));
+    /// assert_eq!(n1.rem_u64(1_000_000_007), Ok(123456));
+    /// assert_eq!(n2.rem_u64(1_000_000_007), Ok(814816192));
+    /// assert_eq!(BigNum::from(u64::MAX).rem_u64(u64::MAX - 58), Ok(58));
+    /// assert!(n1.rem_u64(0).is_err());
+    /// assert!(BigNum::from_string("1.5").unwrap().rem_u64(7).is_err());
+    /// ```
+    pub fn rem_u64(&self, m: u64) -> Result<u64, String> {
+        assert_err!(m != 0, "Cannot compute a remainder modulo 0");
+        assert_err!(self.is_integer(), "The BigNum must be an integer");
+
+        // u128 prevents any overflow of 10 * r + d, as r < m <= u64::MAX
+        let res = self.abs.iter().rev().fold(0u128, |r, d| (10 * r + *d as u128) % m as u128);
+        Ok(res as u64)
+    }
+
+
+
+
+
+    /// Return a [LongDivision], computing the digits after the dot of `num / denom` one by one.  
+    /// Unlike [BigNum::bn_div_prec], the precision does not need to be known in advance.
+    /// 
+    /// # Arguments
+    /// * `num` - the numerator of the division
+    /// * `denom` - the denominator of the division. Must not be zero
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let mut division = BigNum::long_division(&BigNum::from(1), &BigNum::from(7)).unwrap();
+    /// let digits: Vec<u8> = (0..20).map(|_| division.next_digit().unwrap()).collect();
+    /// 
+    /// assert_eq!(division.integer_part(), &BigNum::zero());
+    /// assert_eq!(digits, vec![1, 4, 2, 8, 5, 7, 1, 4, 2, 8, 5, 7, 1, 4, 2, 8, 5, 7, 1, 4]);
+    /// 
+    /// // the digits stop when the division is exact
+    /// let division = BigNum::long_division(&BigNum::from(-9), &BigNum::from_string("0.8").unwrap()).unwrap();
+    /// assert_eq!(division.integer_part(), &BigNum::from(-11));
+    /// assert_eq!(division.collect::<Vec<u8>>(), vec![2, 5]);
+    /// 
+    /// assert!(BigNum::long_division(&BigNum::one(), &BigNum::zero()).is_err());
+    /// ```
+    pub fn long_division(num: &BigNum, denom: &BigNum) -> Result<LongDivision, String> {
+        assert_err!(!denom.is_zero(), "Division by zero");
+        let negative = num.negative != denom.negative;
+
+        // num / denom = (num * 10^power) / (denom * 10^power), which are both integers
+        let (num, denom) = BigNum::aligned(&num.abs(), &denom.abs());
+        let num = BigNum::new(false, num.abs, 0)?;
+        let denom = BigNum::new(false, denom.abs, 0)?;
+
+        let (integer_part, remainder) = BigNum::int_div_rem(&num, &denom)?;
+
+        Ok(LongDivision {
+            negative,
+            integer_part: if negative {integer_part.opposite()} else {integer_part},
+            remainder,
+            denom
+        })
+    }
+
+
+
+
+    /// Compute the power to the nth of the given [BigNum].
+    ///
+    /// # Arguments
+    /// 
+    /// * `n` - a [BigNum]
+    /// * `p` - an i32 representing the power. As of now, decimal powers are not supported
+    /// 
+    /// # Examples
+    /// ```
+    /// use pilosa::BigNum;
+    /// 
+    /// let n1 = BigNum::from_string("123").unwrap();
+    /// let n2 = BigNum::from_string("-10").unwrap();
+    /// let n3 = BigNum::from_string("2.5").unwrap();
+    /// 
+    /// assert_eq!(BigNum::bn_pow(&n1, 5), BigNum::from_string("28153056843").unwrap());
+    /// assert_eq!(BigNum::bn_pow(&n1, 1), BigNum::from_string("123").unwrap());
+    /// assert_eq!(BigNum::bn_pow(&n2, -3), BigNum::from_string("-0.001").unwrap());
+    /// assert_eq!(BigNum::bn_pow(&n3, 5), BigNum::from_string("97.65625").unwrap());
+    /// assert_eq!(BigNum::bn_pow(&n1, 0), BigNum::one());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero and `p` is negative. See [BigNum::try_pow] for a non-panicking version
+    pub fn bn_pow(n: &BigNum, p: i32) -> BigNum {BigNum::bn_pow_iter(n, p).unwrap()}
+
+
+
+    /// Compute the power to the nth of the given [BigNum], returning an error instead of panicking.
+    /// Following the usual convention, `0^0` is `1`. See [BigNum::bn_pow_iter]
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - a [BigNum]
+    /// * `p` - an i32 representing the power
+    ///
+    /// # Examples
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let zero = BigNum::zero();
+    ///
+    /// assert_eq!(BigNum::try_pow(&zero, 0), Ok(BigNum::one()));
+    /// assert_eq!(BigNum::try_pow(&zero, 3), Ok(BigNum::zero()));
+    /// assert!(BigNum::try_pow(&zero, -1).is_err());
+    /// assert_eq!(BigNum::try_pow(&BigNum::from(2), -2), Ok(BigNum::from_string("0.25").unwrap()));
+    /// ```
+    pub fn try_pow(n: &BigNum, p: i32) -> Result<BigNum, String> {BigNum::bn_pow_iter(n, p)}
+
+
 
-        // if both are negative, calculations may vary
-        let neg = n1.negative && n2.negative;
+    /// Compute the power to the nth of the given [BigNum], using the square-and-multiply method
+    /// without any recursion. The bits of `|p|` are read from the most significant one: the result is
+    /// squared for each bit, then multiplied (or divided if `p` is negative) by `n` if the bit is set.  
+    /// Returns an error if `n` is zero and `p` is negative. `0^0` is `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - a [BigNum]
+    /// * `p` - an i32 representing the power
+    ///
+    /// # Examples
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// // reference implementation, computing n^(p/2) recursively
+    /// fn pow_rec(n: &BigNum, p: i32) -> BigNum {
+    ///     if p == 0 {return BigNum::one()}
+    ///     if p == 1 {return n.clone()}
+    ///     let temp = pow_rec(n, p / 2);
+    ///     if p % 2 == 0 {&temp * &temp}
+    ///     else if p > 0 {&(&temp * &temp) * n}
+    ///     else {&(&temp * &temp) / n}
+    /// }
+    ///
+    /// for base in ["2", "-3", "0.5", "1.7", "-12.25"] {
+    ///     let base = BigNum::from_string(base).unwrap();
+    ///     for p in -10..=10 {
+    ///         assert_eq!(BigNum::bn_pow_iter(&base, p), Ok(pow_rec(&base, p)));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(BigNum::bn_pow_iter(&BigNum::zero(), 0), Ok(BigNum::one()));
+    /// assert!(BigNum::bn_pow_iter(&BigNum::zero(), -2).is_err());
+    /// ```
+    pub fn bn_pow_iter(n: &BigNum, p: i32) -> Result<BigNum, String> {
+        if p == 0 {return Ok(BigNum::one())}
+        assert_err!(p > 0 || !n.is_zero(), "Cannot raise 0 to a negative power ({})", p);
 
-        // easy cmp with the number of whole digits
-        if n1.abs.len() - n1.power as usize != n2.abs.len() - n2.power as usize {
-            if neg {return (n1.abs.len() - n1.power as usize) > (n2.abs.len() - n2.power as usize)}
-            else {return (n1.abs.len() - n1.power as usize) < (n2.abs.len() - n2.power as usize)}
-        }
+        let e = p.unsigned_abs();
 
-        // Same amount of digits before the '.', so we can compare each digit one by one
-        let min_len = std::cmp::min(n1.abs.len(), n2.abs.len());
-        let len_n1 = n1.abs.len();
-        let len_n2 = n2.abs.len();
+        // the most significant bit of e is always set
+        let mut res = if p > 0 {n.clone()} else {BigNum::bn_div(&BigNum::one(), n)?};
 
-        for i in 0..min_len {
-            let d1 = &n1.abs[len_n1 - i - 1];
-            let d2 = &n2.abs[len_n2 - i - 1];
+        for i in (0..u32::BITS - 1 - e.leading_zeros()).rev() {
+            res = BigNum::try_mul(&res, &res)?;
 
-            if neg {
-                if d1 < d2 {return false}
-                if d1 > d2 {return true}
-            }
-            else {
-                if d1 < d2 {return true}
-                if d1 > d2 {return false}
+            if (e >> i) & 1 == 1 {
+                res = if p > 0 {BigNum::try_mul(&res, n)?} else {BigNum::bn_div(&res, n)?};
             }
         }
 
-        // at this point we reach the end of at least one BigNum
-        if neg {
-            len_n2 == min_len && len_n1 != min_len
-        }
-        else {
-            len_n1 == min_len && len_n2 != min_len
-        }
+        Ok(res)
     }
 
 
 
 
-    /// Return true if n1 > n2
-    fn is_greater(n1: &BigNum, n2: &BigNum) -> bool {
-        !BigNum::are_equal(n1, n2) && !BigNum::is_lower(n1, n2)
+    /// Return an iterator yielding the successive powers of `base`: `base^0, base^1, base^2, ...`  
+    /// This is more efficient than calling [BigNum::bn_pow] with increasing exponents.
+    ///
+    /// # Examples
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let powers: Vec<BigNum> = BigNum::powers(&BigNum::from(2)).take(5).collect();
+    /// assert_eq!(powers, [1, 2, 4, 8, 16].map(BigNum::from));
+    ///
+    /// let powers: Vec<String> = BigNum::powers(&BigNum::from_string("0.5").unwrap()).take(5).map(|n| n.to_string()).collect();
+    /// assert_eq!(powers, vec!["1", "0.5", "0.25", "0.125", "0.0625"]);
+    /// ```
+    pub fn powers(base: &BigNum) -> Powers {
+        Powers { base: base.clone(), current: BigNum::one() }
     }
 
 
-    /// If n is a power of ten, return x so that n = 10^x
-    /// 
+
+
+    /// Compute the nth number of the Fibonacci sequence.
+    /// Uses the fast doubling method, so only `O(log n)` additions and multiplications are needed:
+    /// - `F(2k) = F(k) * (2*F(k+1) - F(k))`
+    /// - `F(2k+1) = F(k)^2 + F(k+1)^2`
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the index of the Fibonacci number to compute (`F(0) = 0`, `F(1) = 1`)
+    ///
     /// # Examples
-    /// 
     /// ```
     /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("100").unwrap();
-    /// let n2 = BigNum::from_string("101").unwrap();
-    /// let n3 = BigNum::from_string("1").unwrap();
-    /// 
-    /// assert_eq!(n1.is_power_of_ten(), Some(2));
-    /// assert_eq!(n2.is_power_of_ten(), None);
-    /// assert_eq!(n3.is_power_of_ten(), Some(0));
+    ///
+    /// assert_eq!(BigNum::fibonacci(0), BigNum::zero());
+    /// assert_eq!(BigNum::fibonacci(1), BigNum::one());
+    /// assert_eq!(BigNum::fibonacci(2), BigNum::one());
+    /// assert_eq!(BigNum::fibonacci(10), BigNum::from_i32(55).unwrap());
+    /// assert_eq!(BigNum::fibonacci(50), BigNum::from_string("12586269025").unwrap());
+    /// assert_eq!(BigNum::fibonacci(1000).to_string().len(), 209);
     /// ```
-    pub fn is_power_of_ten(&self) -> Option<isize> {
-        let abs_power = core::is_power_of_ten(&self.abs)?;
-        Some(abs_power as isize - self.power as isize)
+    pub fn fibonacci(n: u64) -> BigNum {
+        // (a, b) = (F(k), F(k+1)), starting with k = 0
+        let mut a = BigNum::zero();
+        let mut b = BigNum::one();
+
+        // iterate over the bits of n, from the most significant one
+        for i in (0..u64::BITS - n.leading_zeros()).rev() {
+            let two_b = BigNum::bn_add(&b, &b);
+            let c = BigNum::bn_mul(&a, &BigNum::bn_sub(&two_b, &a)); // F(2k)
+            let d = BigNum::bn_add(&BigNum::bn_mul(&a, &a), &BigNum::bn_mul(&b, &b)); // F(2k+1)
+
+            if (n >> i) & 1 == 0 {
+                a = c;
+                b = d;
+            }
+            else {
+                b = BigNum::bn_add(&c, &d);
+                a = d;
+            }
+        }
+
+        a
     }
 
 
 
-    /// Return the [BigNum] multiplied by 10^power  
-    /// This is quicker than using the basic multiplication algorithm
-    /// as it's only a matter of adding or removing zeroes in the inner representation.
-    /// 
+
+    /// Compute the nth harmonic number `1/1 + 1/2 + ... + 1/n`.
+    /// Each term is computed using [BigNum::bn_div_prec], so the result is not exact
+    /// and can differ from the real value by up to `n * 10^(-precision)`.
+    ///
     /// # Arguments
-    /// * `power` - A number so that self is multiplied by 10^power
-    /// * `pow_negative` - true = the power of ten is negative (ex: self * -10^3)
-    /// 
+    ///
+    /// * `n` - the number of terms of the sum (`harmonic(0) = 0`)
+    /// * `precision` - the maximum number of digits after the dot of each term. Panics if negative
+    ///
     /// # Examples
-    /// 
     /// ```
     /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("123").unwrap();
-    /// let n2 = BigNum::from_string("0.02423").unwrap();
-    /// 
-    /// assert_eq!(n1.bn_tenpow_mul(2, false), BigNum::from_string("12300").unwrap());
-    /// assert_eq!(n2.bn_tenpow_mul(3, true), BigNum::from_string("-24.23").unwrap());
+    ///
+    /// assert_eq!(BigNum::harmonic(0, 10), BigNum::zero());
+    /// assert_eq!(BigNum::harmonic(1, 10), BigNum::one());
+    /// assert_eq!(BigNum::harmonic(1, 0), BigNum::one());
+    /// assert_eq!(BigNum::harmonic(4, 10), BigNum::from_string("2.0833333333").unwrap());
     /// ```
-    pub fn bn_tenpow_mul(&self, power: usize, pow_negative: bool) -> BigNum {
-        // result values
-        let mut final_power = self.power;
-        let mut abs = self.abs.clone();
-
-        // i lied
-        let mut power = power;
-        while power > 0 {
-            if final_power > 0 {final_power -= 1;}
-            else {abs.insert(0, 0);}
-            power -= 1;
+    pub fn harmonic(n: u64, precision: i64) -> BigNum {
+        let mut res = BigNum::zero();
+        let one = BigNum::one();
+
+        for i in 1..=n {
+            let denom = BigNum::from(i);
+            let term = BigNum::bn_div_prec(&one, &denom, precision).expect("the precision cannot be negative");
+            res = BigNum::bn_add(&res, &term);
         }
 
-        let mut res = BigNum {negative: self.negative != pow_negative, abs: abs, power: final_power};
-        res.clean();
         res
     }
 
 
 
-    /// Return the [BigNum] divided by 10^power  
-    /// This is -way- quicker than using the basic division algorithm
-    /// as it's only a matter of adding or removing zeroes in the inner representation.
-    /// 
+
+
+    /// Compute the sum of the first `n` terms of a geometric series,
+    /// i.e `first + first*ratio + ... + first*ratio^(n-1) = first * (1 - ratio^n) / (1 - ratio)`.
+    /// If `ratio` is 1, the result is `first * n`.
+    ///
     /// # Arguments
-    /// * `power` - A number so that [BigNum] is divided by 10^power
-    /// * `pow_negative` - true = the power of ten is negative (ex: self * -10^3)
-    /// 
+    ///
+    /// * `first` - the first term of the series
+    /// * `ratio` - the ratio between two consecutive terms
+    /// * `n` - the number of terms of the sum. Must fit in an i32
+    /// * `precision` - the maximum number of digits after the dot of the division
+    ///
     /// # Examples
-    /// 
     /// ```
     /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("123").unwrap();
-    /// let n2 = BigNum::from_string("0.02423").unwrap();
-    /// let n3 = BigNum::from_string("-2498.244").unwrap();
-    /// 
-    /// assert_eq!(n1.bn_tenpow_div(2, false), BigNum::from_string("1.23").unwrap());
-    /// assert_eq!(n2.bn_tenpow_div(3, true), BigNum::from_string("-0.00002423").unwrap());
-    /// assert_eq!(n3.bn_tenpow_div(0, false), BigNum::from_string("-2498.244").unwrap());
+    ///
+    /// let one = BigNum::one();
+    /// let half = BigNum::from_string("0.5").unwrap();
+    ///
+    /// assert_eq!(BigNum::geometric_sum(&one, &BigNum::from(2), 4, 10), Ok(BigNum::from(15)));
+    /// assert_eq!(BigNum::geometric_sum(&one, &half, 3, 10), Ok(BigNum::from_string("1.75").unwrap()));
+    /// assert_eq!(BigNum::geometric_sum(&half, &one, 7, 10), Ok(BigNum::from_string("3.5").unwrap()));
+    /// assert_eq!(BigNum::geometric_sum(&one, &half, 0, 10), Ok(BigNum::zero()));
     /// ```
-    pub fn bn_tenpow_div(&self, power: isize, pow_negative: bool) -> BigNum {
-        // very simple function as we only need to increase
-        // the n.power by power
-        if power == 0 {return self.clone()}
+    pub fn geometric_sum(first: &BigNum, ratio: &BigNum, n: u64, precision: i64) -> Result<BigNum, String> {
+        let one = BigNum::one();
+        if *ratio == one {return BigNum::try_mul(first, &BigNum::from(n))}
 
-        let mut res = BigNum {negative: self.negative != pow_negative, abs: self.abs.clone(), power: self.power + power as u32};
-        res.clean();
-        res
+        let n = i32::try_from(n).map_err(|_| format!("Too many terms ({})", n))?;
+        let numerator = BigNum::try_mul(first, &(&one - &BigNum::try_pow(ratio, n)?))?;
+        BigNum::bn_div_prec(&numerator, &(&one - ratio), precision)
     }
 
 
 
 
-    /// Return the multiplication of 2 [BigNum]
-    pub fn bn_mul(n1: &BigNum, n2: &BigNum) -> BigNum {
-        // Maybe we could check if n2 is a power of ten to use bn_tenpow_mu; here
-        // i don't know if it is worth it
+    /// Return an iterator over the values from `start` (included) to `end` (excluded), spaced by `step`.  
+    /// A negative `step` yields decreasing values. The iterator is empty if `start` is already past `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let ints: Vec<BigNum> = BigNum::range(BigNum::zero(), BigNum::from(3), BigNum::one()).collect();
+    /// assert_eq!(ints, vec![BigNum::zero(), BigNum::one(), BigNum::from(2)]);
+    ///
+    /// let half = BigNum::from_string("0.5").unwrap();
+    /// let halves: Vec<String> = BigNum::range(BigNum::zero(), BigNum::from(2), half.clone()).map(|n| n.to_string()).collect();
+    /// assert_eq!(halves, vec!["0", "0.5", "1", "1.5"]);
+    ///
+    /// let down: Vec<String> = BigNum::range(BigNum::one(), BigNum::zero(), half.opposite()).map(|n| n.to_string()).collect();
+    /// assert_eq!(down, vec!["1", "0.5"]);
+    ///
+    /// assert_eq!(BigNum::range(BigNum::from(2), BigNum::from(2), half.clone()).count(), 0);
+    /// assert_eq!(BigNum::range(BigNum::from(3), BigNum::from(2), half).count(), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero
+    pub fn range(start: BigNum, end: BigNum, step: BigNum) -> impl Iterator<Item = BigNum> {
+        assert!(!step.is_zero(), "The step of a range cannot be zero");
+        let descending = step.is_negative();
+
+        std::iter::successors(Some(start), move |n| Some(n + &step))
+            .take_while(move |n| if descending {*n > end} else {*n < end})
+    }
 
-        let sign = n1.negative != n2.negative;
-        let abs = core::ub_mul(&n1.abs, &n2.abs);
-        let pow = n1.power + n2.power;
 
-        let mut res = BigNum { negative: sign, abs: abs, power: pow };
+
+
+
+    /// Write the decimal representation of the [BigNum] into the given writer.
+    /// This is what [std::fmt::Display] uses, but it allows writing several numbers
+    /// into the same buffer without creating a `String` for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - the writer (ex: a `String`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("-245.242").unwrap();
+    /// let n2 = BigNum::from_string("0.0012").unwrap();
+    /// let n3 = BigNum::from_string("3000").unwrap();
+    ///
+    /// let mut buffer = String::new();
+    /// for n in [&n1, &n2, &n3] {
+    ///     n.write_to(&mut buffer).unwrap();
+    ///     buffer.push(' ');
+    /// }
+    ///
+    /// assert_eq!(buffer, format!("{} {} {} ", n1, n2, n3));
+    /// assert_eq!(buffer, "-245.242 0.0012 3000 ");
+    ///
+    /// // values with a lot of leading zeroes are written quickly
+    /// let n4 = BigNum::new(true, vec![1], 50_001).unwrap(); // -10^-50001
+    /// let string = n4.to_string();
+    /// assert_eq!(string.len(), 50_004);
+    /// assert!(string.starts_with("-0.000"));
+    /// assert!(string.ends_with("0001"));
+    /// ```
+    pub fn write_to<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        if self.negative {write!(w, "-")?};
         
-        res.clean();
-        res
+        let nb_digits = self.abs.len(); 
+        let dot_pos = nb_digits as isize - self.power as isize;
+
+        // special case if |self| < 1
+        // the leading zeroes are written at once, as there can be a lot of them
+        if dot_pos <= 0 {
+            write!(w, "0.{}", "0".repeat(-dot_pos as usize))?;
+        }
+        for i in 0..nb_digits {
+            if i == dot_pos as usize && i > 0 {write!(w, ".")?};
+            write!(w, "{}", self.abs[nb_digits - i - 1])?;
+        };
+
+        Ok(())
     }
 
 
 
 
-    /// Return the "euclidian" quotient and remainder of num / denom.  
-    /// More precisely, it returns `q` and `r` so that `num = denom * q + r` with `r < denom`    
-    /// **Note:** division by zero is not allowed as, if `denom = 0`, we have `num = 0 * q + r`. In that case `q` does not have a defined value.
-    /// 
-    /// # Arguments
-    /// * `num` - the numerator of the division, >= 0
-    /// * `denum` - the denominator of the division, > 0
-    /// 
+    /// Return whether the BigNum is negative, the digits of its integer part (at least `"0"`),
+    /// and the digits of its decimal part (empty for integers, without trailing zeroes).
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("1332").unwrap();
-    /// let n2 = BigNum::from_string("12").unwrap();
-    /// let n3 = BigNum::zero();
-    /// 
-    /// assert_eq!(BigNum::euclidian(&n1, &n2), Ok((BigNum::from_string("111").unwrap(), BigNum::from_string("0").unwrap()))); 
-    /// assert_eq!(BigNum::euclidian(&n2, &n1), Ok((BigNum::zero(), BigNum::from_string("12").unwrap()))); 
-    /// assert!(BigNum::euclidian(&n1, &n3).is_err());
+    ///
+    /// let parts = |s| BigNum::from_string(s).unwrap().to_parts_strings();
+    /// let strings = |neg, int: &str, dec: &str| (neg, int.to_string(), dec.to_string());
+    ///
+    /// assert_eq!(parts("-12.34"), strings(true, "12", "34"));
+    /// assert_eq!(parts("0.5"), strings(false, "0", "5"));
+    /// assert_eq!(parts("7"), strings(false, "7", ""));
+    /// assert_eq!(parts("-0.0025"), strings(true, "0", "0025"));
+    /// assert_eq!(parts("1200"), strings(false, "1200", ""));
     /// ```
-    pub fn euclidian(num: &BigNum, denom: &BigNum) -> Result<(BigNum, BigNum), String> {
-        assert_err!(!denom.is_zero(), "Division by zero");
-        assert_err!(!num.is_negative(), "The numerator cannot be negative");
-        assert_err!(!denom.is_negative(), "The denominator cannot be negative");
+    pub fn to_parts_strings(&self) -> (bool, String, String) {
+        let to_string = |digits: &[u8]| digits.iter().rev().map(|d| (b'0' + d) as char).collect::<String>();
+        let split = std::cmp::min(self.power as usize, self.abs.len());
 
-        let mut remainder = num.clone();
-        let mut quotient = BigNum::zero();
+        let mut integer = to_string(&self.abs[split..]);
+        if integer.is_empty() {integer.push('0');}
 
-        while &remainder >= &denom {
-            remainder = &remainder - denom;
-            quotient = quotient + BigNum::one();
+        // leading zeroes of the decimal part, not stored in abs
+        let mut decimal = "0".repeat(self.power as usize - split);
+        decimal.push_str(&to_string(&self.abs[..split]));
+
+        (self.negative, integer, decimal)
+    }
+
+
+
+
+
+    /// Encode the BigNum into a compact binary form, that can be decoded with [BigNum::from_bytes]:
+    /// - a flag byte (bit 0: negative, bit 1: odd number of digits)
+    /// - the power, as a LEB128 varint
+    /// - the digits packed 2 per byte (BCD), least significant first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = BigNum::from_string("-123.45").unwrap();
+    /// assert_eq!(n.to_bytes(), vec![0b11, 2, 0x45, 0x23, 0x01]);
+    /// assert_eq!(BigNum::from_bytes(&n.to_bytes()).unwrap(), n);
+    ///
+    /// assert_eq!(BigNum::zero().to_bytes(), vec![0b10, 0, 0x00]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_size());
+        bytes.push(self.negative as u8 | ((self.abs.len() % 2) as u8) << 1);
+
+        let mut power = self.power;
+        loop {
+            let byte = (power & 0x7f) as u8;
+            power >>= 7;
+            if power == 0 {bytes.push(byte); break;}
+            bytes.push(byte | 0x80);
         }
 
-        Ok((quotient, remainder))
+        bytes.extend(self.abs.chunks(2).map(|pair| pair[0] | pair.get(1).unwrap_or(&0) << 4));
+        bytes
+    }
+
+
+
+    /// Decode a BigNum encoded with [BigNum::to_bytes].
+    /// Return an error if the bytes are truncated or do not represent a valid BigNum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n = BigNum::from_string("0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001").unwrap();
+    /// assert_eq!(BigNum::from_bytes(&n.to_bytes()).unwrap(), n);
+    ///
+    /// let tiny = BigNum::new(false, vec![1], u32::MAX).unwrap();
+    /// assert_eq!(BigNum::from_bytes(&tiny.to_bytes()), Ok(tiny));
+    ///
+    /// assert!(BigNum::from_bytes(&[0, 0]).is_err());           // no digit
+    /// assert!(BigNum::from_bytes(&[0, 0x80]).is_err());        // truncated power
+    /// assert!(BigNum::from_bytes(&[0b10, 0xff, 0xff, 0xff, 0xff, 0x7f, 0x01]).is_err()); // power overflow
+    /// assert!(BigNum::from_bytes(&[0b10, 0x85, 0x00, 0x01]).is_err());                 // overlong power
+    /// assert!(BigNum::from_bytes(&[0, 0, 0x0a]).is_err());     // invalid digit
+    /// assert!(BigNum::from_bytes(&[0, 0, 0x01]).is_err());     // useless significant zero
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<BigNum, String> {
+        assert_err!(!bytes.is_empty(), "Error: no flag byte");
+        let (flags, mut rest) = (bytes[0], &bytes[1..]);
+        assert_err!(flags < 4, "Error: invalid flag byte {:#04x}", flags);
+
+        let mut power: u32 = 0;
+        let mut shift = 0;
+        loop {
+            assert_err!(!rest.is_empty(), "Error: truncated power");
+            let byte = rest[0];
+            rest = &rest[1..];
+
+            // the 5th byte only holds the 4 highest bits of the power
+            assert_err!(shift < 28 || (shift == 28 && byte & 0xf0 == 0), "Error: power overflow");
+            power |= ((byte & 0x7f) as u32) << shift;
+
+            if byte & 0x80 == 0 {
+                // each power has a single encoding
+                assert_err!(shift == 0 || byte != 0, "Error: overlong power encoding");
+                break;
+            }
+            shift += 7;
+        }
+
+        let mut abs: Vec<u8> = rest.iter().flat_map(|b| [b & 0x0f, b >> 4]).collect();
+        if flags & 0b10 != 0 {
+            assert_err!(abs.pop() == Some(0), "Error: invalid padding digit");
+        }
+
+        let res = BigNum {negative: flags & 1 != 0, abs, power};
+        res.validate()?;
+        Ok(res)
     }
 
 
 
+    /// Return the number of bytes of the [BigNum::to_bytes] encoding, without encoding the BigNum.
+    /// Useful to pre-size buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// for s in ["0", "-1", "12", "-123.45", "0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"] {
+    ///     let n = BigNum::from_string(s).unwrap();
+    ///     assert_eq!(n.byte_size(), n.to_bytes().len());
+    /// }
+    ///
+    /// let big = BigNum::bn_pow(&BigNum::from(7), 1000);
+    /// assert_eq!(big.byte_size(), big.to_bytes().len());
+    /// ```
+    pub fn byte_size(&self) -> usize {
+        let power_bits = 32 - self.power.leading_zeros() as usize;
+        let power_bytes = std::cmp::max(1, power_bits.div_ceil(7));
+        1 + power_bytes + self.abs.len().div_ceil(2)
+    }
 
 
 
-    /// Return the sum of 2 BigNums of the same sign.
-    fn inner_add(n1: &BigNum, n2: &BigNum) -> BigNum {
-        if n1.negative != n2.negative {panic!("inner_add can only add BigNums of the same sign")}
 
-        let mut n1 = n1.clone();
-        let mut n2 = n2.clone();
-        BigNum::same_power(&mut n1, &mut n2);
+    /// Return a byte string such that comparing the keys lexicographically gives the same order
+    /// as comparing the BigNums, which allows byte-wise (radix) sorting of BigNums.
+    /// The key is made of:
+    /// - a sign byte (0: negative, 1: zero, 2: positive)
+    /// - the place of the most significant digit, as 8 big-endian bytes with the sign bit flipped
+    /// - the digits + 1, most significant first, without the trailing zeroes and followed by a 0 terminator
+    ///
+    /// For negative values, every byte after the sign byte is inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let mut values: Vec<BigNum> = ["-12.3", "-12.34", "0", "1200", "0.0012", "-0.5", "1.2", "1.23", "-1200", "99.9", "100"]
+    ///     .iter().map(|s| BigNum::from_string(s).unwrap()).collect();
+    /// let mut by_key = values.clone();
+    ///
+    /// values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// by_key.sort_by_key(|n| n.sort_key());
+    /// assert_eq!(values, by_key);
+    ///
+    /// assert_eq!(BigNum::from_string("12.30").unwrap().sort_key(), BigNum::from_string("12.3").unwrap().sort_key());
+    /// ```
+    pub fn sort_key(&self) -> Vec<u8> {
+        if self.is_zero() {return vec![1]}
 
-        // Create the new value
-        let sum = core::ub_add(n1.abs, n2.abs);
-        let mut res = BigNum {
-            negative: n1.negative, // n2.negative would work too (as n1.negative == n2.negative)
-            abs: sum,   
-            power: n1.power        // same
-        };
+        let place = self.abs.len() as i64 - 1 - self.power as i64;
+        let trailing_zeroes = self.abs.iter().take_while(|d| **d == 0).count();
 
-        res.clean();
-        res
+        let mut key = Vec::with_capacity(10 + self.abs.len() - trailing_zeroes);
+        key.extend(((place as u64) ^ (1 << 63)).to_be_bytes());
+        key.extend(self.abs[trailing_zeroes..].iter().rev().map(|d| d + 1));
+        key.push(0);
+
+        // a greater magnitude means a lower negative value
+        if self.negative {key.iter_mut().for_each(|b| *b = !*b);}
+
+        key.insert(0, if self.negative {0} else {2});
+        key
     }
 
 
 
 
+    /// Return a stable 64-bit FNV-1a hash of the sign, digits and power of the BigNum,
+    /// usable as a cache key. As BigNums are cleaned, equal values always have the same hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("12.500").unwrap();
+    /// let n2 = BigNum::from_string("0012.5").unwrap();
+    /// assert_eq!(n1.value_hash(), n2.value_hash());
+    /// assert_eq!(BigNum::from_string("-0").unwrap().value_hash(), BigNum::zero().value_hash());
+    ///
+    /// // 1499 distinct values: -500..500 and 0.001..0.5
+    /// let mut hashes: Vec<u64> = (-500..500).map(|i| BigNum::from(i).value_hash())
+    ///     .chain((1..500).map(|i| BigNum::from(i).bn_tenpow_div(3, false).value_hash()))
+    ///     .collect();
+    /// hashes.sort();
+    /// hashes.dedup();
+    /// assert_eq!(hashes.len(), 1499);
+    /// ```
+    pub fn value_hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        std::iter::once(self.negative as u8)
+            .chain(self.power.to_le_bytes())
+            .chain(self.abs.iter().copied())
+            .fold(FNV_OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+    }
 
 
-    /// Return the diff of 2 positive BigNums.
-    /// panics if n1 < n2
-    fn inner_sub(n1: &BigNum, n2: &BigNum) -> BigNum {
-        if n1.negative || n2.negative {panic!("inner_sub can only substract positive BigNums")}
-        if n1 < n2 {panic!("inner_sub requires n1 > n2")}
 
-        let mut n1 = n1.clone();
-        let mut n2 = n2.clone();
 
-        BigNum::same_power(&mut n1, &mut n2);
-        BigNum::same_digit_amount(&mut n1, &mut n2);
 
-        let mut res = BigNum::new(false, core::ub_sub(n1.abs, n2.abs).expect("internal error in inner_sub"), n1.power).unwrap();
+    /// Return a short, one-line description of the BigNum, for logging purposes.  
+    /// The value is fully written only if it takes at most 30 characters (leading zeroes and dot included):
+    /// otherwise only its first and last characters are shown, without writing the whole value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pilosa::BigNum;
+    ///
+    /// let n1 = BigNum::from_string("-123.45").unwrap();
+    /// let n2 = BigNum::bn_pow(&BigNum::from(2), 200);
+    ///
+    /// assert_eq!(n1.summary(), "sign=-, digits=5, scale=2, value≈-123.45");
+    /// assert_eq!(n2.summary(), "sign=+, digits=61, scale=0, value≈1606938044…2835301376");
+    ///
+    /// // leading zeroes count in the length of the value
+    /// let n3 = BigNum::from_string("-0.0000000000000000000000000123456789").unwrap();
+    /// assert_eq!(n3.summary(), "sign=-, digits=9, scale=34, value≈-0.00000000…0123456789");
+    /// assert_eq!(BigNum::ten_pow(-100000).summary(), "sign=+, digits=1, scale=100000, value≈0.00000000…0000000001");
+    /// ```
+    pub fn summary(&self) -> String {
+        let sign = if self.negative {'-'} else {'+'};
 
-        res.clean();
-        res
+        // length of the written value (without its sign): integer part (at least "0"), dot and decimal part
+        let (len, power) = (self.abs.len(), self.power as usize);
+        let int_len = len.saturating_sub(power).max(1);
+        let width = if power > 0 {int_len + 1 + power} else {int_len};
+
+        let value = if width <= SUMMARY_MAX_DIGITS {self.to_string()}
+        else {
+            // i-th character of the written value, computed from abs and power
+            let char_at = |i: usize| {
+                let digit = if i < int_len {
+                    if len > power {self.abs[len - 1 - i]} else {0}
+                } else if i == int_len {
+                    return '.';
+                } else {
+                    // the decimal at 10^-(j+1)
+                    let j = i - int_len - 1;
+                    *self.abs.get(power - 1 - j).unwrap_or(&0)
+                };
+                (b'0' + digit) as char
+            };
+
+            let head: String = (0..SUMMARY_EDGE_DIGITS).map(char_at).collect();
+            let tail: String = (width - SUMMARY_EDGE_DIGITS..width).map(char_at).collect();
+            format!("{}{head}…{tail}", if self.negative {"-"} else {""})
+        };
+
+        format!("sign={}, digits={}, scale={}, value≈{}", sign, self.abs.len(), self.power, value)
     }
 
 
 
-    
 
 
-    /// Add two [BigNum] together
+    /// Return the relative error `|approx - exact| / |exact|`, with a maximum of `precision` digits after the dot.
     /// 
+    /// # Arguments
+    /// * `approx` - the approximated value
+    /// * `exact` - the exact value. Must not be zero
+    /// * `precision` - the maximum number of digits after the dot, >= 0
+    ///
     /// # Examples
     /// 
     /// ```
     /// use pilosa::BigNum;
     /// 
-    /// let n1 = BigNum::from_string("53643.368359").unwrap();
-    /// let n2 = BigNum::from_string("-22398247.24982").unwrap();
-    /// let n3 = BigNum::zero();
-    /// let n4 = BigNum::from_string("-32089").unwrap();
-    /// let n5 = BigNum::from_string("209").unwrap();
+    /// let exact = BigNum::from_string("1.414213562373095").unwrap();
+    /// let approx = BigNum::from_string("1.414213562373099").unwrap();
+    /// let bound = BigNum::from_string("0.0000000000001").unwrap(); // 10^-13
     /// 
-    /// assert_eq!(BigNum::bn_add(&n1, &n1), BigNum::from_string("107286.736718").unwrap());
-    /// assert_eq!(BigNum::bn_add(&n1, &n2), BigNum::from_string("-22344603.881461").unwrap());
-    /// assert_eq!(BigNum::bn_add(&n2, &n3), BigNum::from_string("-22398247.24982").unwrap());
-    /// assert_eq!(BigNum::bn_add(&n1, &n1), BigNum::from_string("107286.736718").unwrap());
-    /// assert_eq!(BigNum::bn_add(&n4, &n5), BigNum::from_string("-31880").unwrap());
+    /// assert!(BigNum::relative_error(&approx, &exact, 20).unwrap() < bound);
+    /// assert_eq!(BigNum::relative_error(&BigNum::from(-90), &BigNum::from(-100), 5), Ok(BigNum::from_string("0.1").unwrap()));
+    /// assert!(BigNum::relative_error(&approx, &BigNum::zero(), 20).is_err());
     /// ```
-    pub fn bn_add(n1: &BigNum, n2: &BigNum) -> BigNum {
-        // Transform the addition in order to use inner_add (addition of same sign)
-        // or inner_sub (substraction of positive BigNums)
-        match (n1.negative, n2.negative) {
-            (false, false) => { // x + y
-                BigNum::inner_add(n1, n2)
-            },
-            (true, true) => { // -x + -y
-                BigNum::inner_add(n1, n2)
-            },
-            (true, false) => { // -x + y <=> y - x
-                n2 - &n1.opposite()
-            },
-            (false, true) => { // x + -y <=> x - y
-                n1 - &n2.opposite()
-            },
-        }
-    }
-
+    pub fn relative_error(approx: &BigNum, exact: &BigNum, precision: i64) -> Result<BigNum, String> {
+        assert_err!(!exact.is_zero(), "The exact value cannot be zero");
 
+        let diff = BigNum::bn_sub(approx, exact).abs();
+        BigNum::bn_div_prec(&diff, &exact.abs(), precision)
+    }
 
 
 
 
-    /// Substract one [BigNum] to another
-    /// 
+    /// Return the change from `from` to `to`, in percent: `(to - from) / |from| * 100`, computed with
+    /// `precision` digits after the dot. An increase is positive and a decrease negative, whatever the sign of `from`.  
+    /// Returns an error if `from` is zero.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("53643.368359").unwrap();
-    /// let n2 = BigNum::from_string("24872398247.24982").unwrap();
-    /// let n3 = BigNum::zero();
-    /// let n4 = BigNum::from_string("-32089").unwrap();
-    /// let n5 = BigNum::from_string("209").unwrap();
-    /// 
-    /// assert_eq!(BigNum::bn_sub(&n1, &n2), BigNum::from_string("-24872344603.881461").unwrap());
-    /// assert_eq!(BigNum::bn_sub(&n2, &n3), BigNum::from_string("24872398247.24982").unwrap());
-    /// assert_eq!(BigNum::bn_sub(&n1, &n1), BigNum::zero());
-    /// assert_eq!(BigNum::bn_sub(&n4, &n5), BigNum::from_string("-32298").unwrap());
+    ///
+    /// let hundred = BigNum::from(100);
+    ///
+    /// assert_eq!(BigNum::percent_change(&hundred, &BigNum::from(150), 5), Ok(BigNum::from(50)));
+    /// assert_eq!(BigNum::percent_change(&hundred, &BigNum::from(50), 5), Ok(BigNum::from(-50)));
+    /// assert_eq!(BigNum::percent_change(&BigNum::from(-200), &BigNum::from(-150), 5), Ok(BigNum::from(25)));
+    /// assert_eq!(BigNum::percent_change(&BigNum::from(3), &BigNum::from(4), 2), Ok(BigNum::from_string("33.33").unwrap()));
+    /// assert!(BigNum::percent_change(&BigNum::zero(), &hundred, 5).is_err());
     /// ```
-    pub fn bn_sub(n1: &BigNum, n2: &BigNum) -> BigNum {
-        let mut res = match (n1.negative, n2.negative) {
-            (false, false) => {
-                if n1 < n2 {BigNum::inner_sub(n2, n1).opposite()} // require n1 > n2 :    (x-y) <=> -(y-x)
-                else {BigNum::inner_sub(n1, n2)}
-            },
-            (true, true) => {  // -x - -y <=> y - x
-                &n2.opposite() - &n1.opposite()
-            },
-            (true, false) => { // -x - y <=> -x + -y
-                n1 + &n2.opposite()
-            },
-            (false, true) => { // x - -y <=> x + y
-                n1 + n2
-            },
-        };
+    pub fn percent_change(from: &BigNum, to: &BigNum, precision: i64) -> Result<BigNum, String> {
+        assert_err!(!from.is_zero(), "Cannot compute a percentage change from zero");
 
-        res.clean();
-        res
+        let diff = BigNum::bn_sub(to, from).bn_tenpow_mul(2, false);
+        BigNum::bn_div_prec(&diff, &from.abs(), precision)
     }
 
 
 
 
 
+    // wrapping functions that return a Result (like the bn_div function) to facilitate the use of
+    // the op_impl macro
+    fn result_bn_sub(n1: &BigNum, n2: &BigNum) -> Result<BigNum, ()> {Ok(BigNum::bn_sub(n1, n2))}
+    fn result_bn_add(n1: &BigNum, n2: &BigNum) -> Result<BigNum, ()> {Ok(BigNum::bn_add(n1, n2))}
+}
 
 
 
-    /// Divide one [BigNum] by another.  
-    /// The result will have a maximum precision of FLOAT_PRECISION digits after the dot. If the result is not perfect (ex: non-decimal values), the result
-    /// will be NOT be rounded, so the actual precision will be +- 10^(-FLOAT_PRECISION)
-    /// 
-    /// # Arguments
-    /// * `n1` - a [BigNum]
-    /// * `n2` - a [BigNum]. Must not be zero or the operation results in an error.
-    ///
-    /// # Examples
-    /// 
-    /// ```
-    /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("1224.235").unwrap();
-    /// let n2 = BigNum::from_string("12").unwrap();
-    /// let n3 = BigNum::from_string("0").unwrap();
-    /// 
-    /// assert_eq!(BigNum::bn_div(&n1, &n2), Ok(BigNum::from_string("102.019583333333333").unwrap())); // considering FLOAT_PRECISION = 15
-    /// assert!(BigNum::bn_div(&n1, &n3).is_err());
-    /// ```
-    pub fn bn_div(n1: &BigNum, n2: &BigNum) -> Result<BigNum, String> {
-        // prevent zero division
-        assert_err!(!n2.is_zero(), "Division by zero");
 
-        // checking if n2 is a power of ten
-        // really worth it (compared to bn_mul) as it could prevent precision lost
-        // (the normal algorithm would return 10 / 100 = 0.0999999999)
-        match n2.is_power_of_ten() {
-            Some(p) => return Ok(BigNum::bn_tenpow_div(n1, p, n2.is_negative())),
-            None => ()
-        };
 
-        let sign = n1.negative != n2.negative;
-        let pow = n1.power as i64 - n2.power as i64; // pow can be negative. If so it will be modified after the division
 
 
-        let mut n1 = n1.clone();
-        let n2 = n2.clone();
 
 
 
-        // increase the power of n1 so that n1.power - n2.power >= FLOAT_PRECISION
-        let delta = FLOAT_PRECISION - pow;
-        if delta > 0 {n1.with_power(n1.power + delta as u32);}
 
+/// Computes lazily the digits after the dot of a division (see [BigNum::long_division]).  
+/// Also implements [Iterator], yielding the same digits as [LongDivision::next_digit].
+#[derive(Clone, Debug)]
+pub struct LongDivision {
+    negative: bool,
+    integer_part: BigNum,
+    remainder: BigNum,
+    denom: BigNum
+}
 
-        let (quotient, _) = if n2.abs.len() == 1 {
-            let (q, r) = core::ub_shortdiv(n1.abs, n2.abs[0]).expect("n2 was not clean when passed to bn_div, resulting in a division by 0"); // n2.abs[0] should not be 0
-            (q, vec![r])
-        } else {
-            core::ub_div(&n1.abs, &n2.abs)?
-        };
-        
-        debug_assert!(n1.power - n2.power > 0, "resulting power is negative");
 
-        // return the cleaned result
-        let mut res = BigNum { negative: sign, abs: quotient, power: n1.power - n2.power as u32};
-        res.clean();
 
-        Ok(res)
+impl LongDivision {
+    /// Return true if the result of the division is negative
+    pub fn is_negative(&self) -> bool {self.negative}
+
+    /// Return the integer part of the result of the division (i.e the result truncated toward zero)
+    pub fn integer_part(&self) -> &BigNum {&self.integer_part}
+
+    /// Return the next digit after the dot of the result of the division,
+    /// or None if all the remaining digits are zeroes (the division is exact)
+    pub fn next_digit(&mut self) -> Option<u8> {
+        if self.remainder.is_zero() {return None}
+
+        // the remainder is < denom, so 10 * remainder / denom is a digit
+        let shifted = self.remainder.bn_tenpow_mul(1, false);
+        let (digit, remainder) = BigNum::int_div_rem(&shifted, &self.denom).expect("internal error in LongDivision");
+        self.remainder = remainder;
+
+        Some(digit.abs[0])
     }
+}
 
 
+impl Iterator for LongDivision {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {self.next_digit()}
+}
 
 
-    /// Compute the power to the nth of the given [BigNum].
-    ///
-    /// # Arguments
-    /// 
-    /// * `n` - a [BigNum]
-    /// * `p` - an i32 representing the power. As of now, decimal powers are not supported
-    /// 
-    /// # Examples
-    /// ```
-    /// use pilosa::BigNum;
-    /// 
-    /// let n1 = BigNum::from_string("123").unwrap();
-    /// let n2 = BigNum::from_string("-10").unwrap();
-    /// let n3 = BigNum::from_string("2.5").unwrap();
-    /// 
-    /// assert_eq!(BigNum::bn_pow(&n1, 5), BigNum::from_string("28153056843").unwrap());
-    /// assert_eq!(BigNum::bn_pow(&n1, 1), BigNum::from_string("123").unwrap());
-    /// assert_eq!(BigNum::bn_pow(&n2, -3), BigNum::from_string("-0.001").unwrap());
-    /// assert_eq!(BigNum::bn_pow(&n3, 5), BigNum::from_string("97.65625").unwrap());
-    /// assert_eq!(BigNum::bn_pow(&n1, 0), BigNum::one());
-    /// ```
-    pub fn bn_pow(n: &BigNum, p: i32) -> BigNum {
-        // exit conditions (this function is recursive)
-        if p == 0 {return BigNum::one()}
-        if p == 1 {return n.clone()}
 
-        // ex: 10^4 = 10^2 * 10^2
-        let temp = BigNum::bn_pow(n, p/2);
 
-        let res = if p % 2 == 0 {&temp * &temp}
-        else if p > 0 {&(&temp * &temp) * n}
-        else {&(&temp * &temp) / n};
+/// Infinite iterator over the successive powers of a [BigNum] (see [BigNum::powers]).  
+/// Each term is computed by multiplying the previous one by the base.
+#[derive(Clone, Debug)]
+pub struct Powers {
+    base: BigNum,
+    current: BigNum
+}
 
-        res
+impl Iterator for Powers {
+    type Item = BigNum;
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = &self.current * &self.base;
+        Some(std::mem::replace(&mut self.current, next))
     }
+}
 
 
 
 
-
-    // wrapping functions that return a Result (like the bn_div function) to facilitate the use of
-    // the op_impl macro
-    fn result_bn_sub(n1: &BigNum, n2: &BigNum) -> Result<BigNum, ()> {Ok(BigNum::bn_sub(n1, n2))}
-    fn result_bn_add(n1: &BigNum, n2: &BigNum) -> Result<BigNum, ()> {Ok(BigNum::bn_add(n1, n2))}
-    fn result_bn_mul(n1: &BigNum, n2: &BigNum) -> Result<BigNum, ()> {Ok(BigNum::bn_mul(n1, n2))}
+/// Compute arbitrary powers of the same base, memoizing the squares `base^(2^k)`
+/// so that each exponent only costs a few multiplications.  
+/// Faster than repeated [BigNum::bn_pow] calls when evaluating many powers of one base.
+///
+/// # Examples
+///
+/// ```
+/// use pilosa::{BigNum, PowerCache};
+///
+/// let base = BigNum::from_string("1.5").unwrap();
+/// let mut cache = PowerCache::new(&base);
+///
+/// for exp in [0, 1, 2, 7, 64, 100, 3] {
+///     assert_eq!(cache.get(exp), BigNum::bn_pow(&base, exp as i32));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PowerCache {
+    squares: Vec<BigNum>
 }
 
+impl PowerCache {
+    /// Create a cache for the powers of `base`
+    pub fn new(base: &BigNum) -> PowerCache {PowerCache { squares: vec![base.clone()] }}
 
+    /// Return `base^exp`, computing and caching the missing squares if needed
+    pub fn get(&mut self, exp: u32) -> BigNum {
+        let nb_bits = (u32::BITS - exp.leading_zeros()) as usize;
+        while self.squares.len() < nb_bits {
+            let last = &self.squares[self.squares.len() - 1];
+            self.squares.push(last * last);
+        }
 
+        (0..nb_bits)
+            .filter(|k| (exp >> k) & 1 == 1)
+            .fold(BigNum::one(), |acc, k| &acc * &self.squares[k])
+    }
+}
 
 
 
@@ -747,25 +4094,7 @@ impl BigNum {
 
 impl std::fmt::Display for BigNum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.negative {write!(f, "-")?};
-        
-        let nb_digits = self.abs.len(); 
-        let mut dot_pos = nb_digits as isize - self.power as isize;
-
-        // special case if |self| < 1
-        if dot_pos <= 0 {
-            write!(f, "0.")?;
-            while dot_pos < 0 {
-                write!(f, "0")?;
-                dot_pos += 1;
-            }
-        }
-        for i in 0..nb_digits {
-            if i == dot_pos as usize && i > 0 {write!(f, ".")?};
-            write!(f, "{}", self.abs[nb_digits - i - 1])?;
-        };
-
-        Ok(())
+        self.write_to(f)
     }
 }
 
@@ -825,5 +4154,87 @@ macro_rules! op_impl {
 
 op_impl!(Add, add, result_bn_add);
 op_impl!(Sub, sub, result_bn_sub);
-op_impl!(Mul, mul, result_bn_mul);
-op_impl!(Div, div, bn_div);
\ No newline at end of file
+op_impl!(Mul, mul, try_mul);
+op_impl!(Div, div, bn_div);
+op_impl!(Rem, rem, bn_rem);
+
+
+/// `%=` uses the truncated division, like the `%` operator (see [BigNum::bn_rem])
+///
+/// # Examples
+///
+/// ```
+/// use pilosa::BigNum;
+///
+/// let mut n = BigNum::from(-7);
+/// n %= BigNum::from(3);
+/// assert_eq!(n, BigNum::from(-1));
+/// ```
+impl RemAssign for BigNum {
+    fn rem_assign(&mut self, rhs: Self) {*self = BigNum::bn_rem(self, &rhs).unwrap()}
+}
+
+
+/// # Examples
+///
+/// ```
+/// use pilosa::BigNum;
+///
+/// let mut n = BigNum::from_string("1.5").unwrap();
+/// n += BigNum::from(2);
+/// n += &BigNum::from_string("-0.25").unwrap();
+/// assert_eq!(n, BigNum::from_string("3.25").unwrap());
+/// ```
+impl AddAssign<&BigNum> for BigNum {
+    fn add_assign(&mut self, rhs: &BigNum) {*self = BigNum::bn_add(self, rhs)}
+}
+impl AddAssign for BigNum {
+    fn add_assign(&mut self, rhs: Self) {*self += &rhs}
+}
+
+
+
+// conversions from integer types.
+// the magnitude is computed with unsigned_abs so that the minimum values (ex: i32::MIN) do not overflow
+macro_rules! from_signed_impl {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigNum {
+            fn from(n: $t) -> Self {BigNum::from_integer_parts(n < 0, n.unsigned_abs() as u128)}
+        }
+    )*};
+}
+
+macro_rules! from_unsigned_impl {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigNum {
+            fn from(n: $t) -> Self {BigNum::from_integer_parts(false, n as u128)}
+        }
+    )*};
+}
+
+
+from_signed_impl!(i8, i16, i32, i64, i128, isize);
+from_unsigned_impl!(u8, u16, u32, u64, u128, usize);
+
+
+/// Conversion from a f64, using its shortest decimal representation (like [BigNum::from_f64]).
+/// Use [BigNum::from_f64_exact] to get the exact value of the f64 instead.  
+/// Fails if the f64 is NaN or infinite.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::BigNum;
+/// 
+/// assert_eq!(BigNum::try_from(-2.5), BigNum::from_string("-2.5"));
+/// assert_eq!(BigNum::try_from(0.1), BigNum::from_string("0.1"));
+/// assert!(BigNum::try_from(f64::NAN).is_err());
+/// assert!(BigNum::try_from(f64::NEG_INFINITY).is_err());
+/// ```
+impl TryFrom<f64> for BigNum {
+    type Error = String;
+    fn try_from(n: f64) -> Result<Self, Self::Error> {
+        assert_err!(n.is_finite(), "Cannot convert a non-finite f64 ({}) into a BigNum", n);
+        BigNum::from_f64(n)
+    }
+}
\ No newline at end of file
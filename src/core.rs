@@ -2,7 +2,64 @@
 //! Those functions are used by the BigNum struct to represent and manipulate
 //! arbitrary long/precise numbers.
 
-use crate::assert_err;
+use std::fmt;
+
+
+
+/// Errors returned by the fallible functions of this module.  
+/// Unlike the `String` errors of [BigNum](crate::BigNum), they do not require any allocation.
+/// They can be converted to a `String` (see [BigNum](crate::BigNum)'s API) using `String::from` or `?`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core::{self, CoreError};
+/// 
+/// assert_eq!(core::ub_divmod(&vec![3, 6, 7, 2], &vec![0]), Err(CoreError::DivByZero));
+/// assert_eq!(core::ub_sub(vec![4, 6, 3], vec![3, 6, 7]), Err(CoreError::Underflow));
+/// assert_eq!(String::from(CoreError::DivByZero), "Division by zero");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoreError {
+    /// The divisor of a division is zero
+    DivByZero,
+    /// The result of a substraction would be negative
+    Underflow,
+    /// The operands do not have the same amount of digits (lengths of u and v)
+    LengthMismatch(usize, usize),
+    /// A value expected to be a digit is not
+    InvalidDigit(u8),
+    /// The divisor must have at least 2 digits
+    DivisorTooShort,
+    /// The dividend must have at least as many digits as the divisor
+    DividendTooShort,
+    /// The most significant digit of the divisor (given) is lower than 5 after normalisation
+    NotNormalized(u8),
+    /// The remainder is not a multiple of the normaliser (the value of remainder % normaliser is given)
+    InexactRemainder(u8)
+}
+
+impl fmt::Display for CoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoreError::DivByZero => write!(f, "Division by zero"),
+            CoreError::Underflow => write!(f, "Expected u >= v"),
+            CoreError::LengthMismatch(u, v) => write!(f, "Both unsigned big ints must have the same amount of digits ({} != {})", u, v),
+            CoreError::InvalidDigit(d) => write!(f, "{} is not a digit", d),
+            CoreError::DivisorTooShort => write!(f, "v needs to be of length 2 at least"),
+            CoreError::DividendTooShort => write!(f, "u can't be shorter than v"),
+            CoreError::NotNormalized(d) => write!(f, "last digit of nv = {} < 5", d),
+            CoreError::InexactRemainder(r0) => write!(f, "r0 = {} != 0", r0),
+        }
+    }
+}
+
+impl std::error::Error for CoreError {}
+
+impl From<CoreError> for String {
+    fn from(e: CoreError) -> String {e.to_string()}
+}
+
 
 /// Clean the unsigned big int (vec of digits from least to most significant) by removing useless zeroes
 /// Will keep one zero if it is already here (ex: `vec![0, 0, 0]` -> `vec![0]`)
@@ -65,6 +122,34 @@ pub fn ub_cleaned(ubint: Vec<u8>) -> Vec<u8> {
 
 
 
+/// Add high zeroes to the unsigned big int so that it holds at least `len` digits.
+/// The represented value is not modified, and nothing is done if `ubint` is already long enough.
+/// 
+/// # Arguments
+/// 
+/// * `ubint` - the unsigned big int (vec of digits from least to most significant)
+/// * `len` - the minimum amount of digits of `ubint`
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// let mut number = vec![0, 2, 4, 9, 6];           // 69420
+/// core::ub_pad_to(&mut number, 8);                // -> 00069420
+/// assert_eq!(number, vec![0, 2, 4, 9, 6, 0, 0, 0]);
+/// assert_eq!(core::ub_cleaned(number.clone()), vec![0, 2, 4, 9, 6]);
+/// 
+/// core::ub_pad_to(&mut number, 3);                // already long enough
+/// assert_eq!(number.len(), 8);
+/// ```
+pub fn ub_pad_to(ubint: &mut Vec<u8>, len: usize) {
+    if ubint.len() < len {ubint.resize(len, 0);}
+}
+
+
+
+
 
 
 
@@ -161,7 +246,7 @@ pub fn ub_add(u: Vec<u8>, v: Vec<u8>) -> Vec<u8> {
 
 
 /// Substract an unsigned big int v to an unsigned big int u.
-/// requires u >= v and u and v of the same size (panics otherwise)  
+/// requires u >= v and u and v of the same size (returns an error otherwise)  
 /// returns a value of the same length, NOT CLEANED  
 /// Based on the substraction algorithm in the Art of Computer Programming
 /// 
@@ -182,10 +267,11 @@ pub fn ub_add(u: Vec<u8>, v: Vec<u8>) -> Vec<u8> {
 /// 
 /// assert_eq!(core::ub_sub(n1, n2), Ok(vec![9, 9, 3, 2]));
 /// assert_eq!(core::ub_sub(n3, n4), Ok(vec![6, 9, 0, 0]));
+/// assert_eq!(core::ub_sub(vec![1], vec![4, 6]), Err(core::CoreError::LengthMismatch(1, 2)));
 /// ```
-pub fn ub_sub(u: Vec<u8>, v: Vec<u8>) -> Result<Vec<u8>, String> {
+pub fn ub_sub(u: Vec<u8>, v: Vec<u8>) -> Result<Vec<u8>, CoreError> {
     // the algorithm requires that u.len() == v.len()
-    assert_err!(u.len() == v.len(), "Both unsigned big ints must have the same amount of digits");
+    if u.len() != v.len() {return Err(CoreError::LengthMismatch(u.len(), v.len()))}
 
     // optimization
     if v == vec![0] {return Ok(u);}
@@ -201,7 +287,7 @@ pub fn ub_sub(u: Vec<u8>, v: Vec<u8>) -> Result<Vec<u8>, String> {
         k = -((t < 0) as i16);
     }
 
-    if k != 0 {panic!("Expected u >= v")}
+    if k != 0 {return Err(CoreError::Underflow)}
 
     Ok(w)
 }
@@ -210,6 +296,54 @@ pub fn ub_sub(u: Vec<u8>, v: Vec<u8>) -> Result<Vec<u8>, String> {
 
 
 
+/// Substract an unsigned big int v to an unsigned big int u.
+/// Unlike [ub_sub], u and v can have different lengths, and None is returned instead of panicking if u < v.  
+/// The result is cleaned.
+/// 
+/// # Arguments
+/// 
+/// * `u` & `v` - unsigned big ints (represented by vecs of u8, from least to most significant digit)
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// let n1 = vec![3, 6, 7, 2];     // 2763
+/// let n2 = vec![4, 6, 3];        // 364
+/// let n3 = vec![0, 0, 1, 0];     // 100 (not cleaned)
+/// 
+/// assert_eq!(core::ub_checked_sub(&n1, &n2), Some(vec![9, 9, 3, 2]));
+/// assert_eq!(core::ub_checked_sub(&n2, &n3), Some(vec![4, 6, 2]));
+/// assert_eq!(core::ub_checked_sub(&n1, &n1), Some(vec![0]));
+/// assert_eq!(core::ub_checked_sub(&n2, &n1), None);
+/// ```
+pub fn ub_checked_sub(u: &[u8], v: &[u8]) -> Option<Vec<u8>> {
+    let n = std::cmp::max(u.len(), v.len());
+    let mut w = vec![0; n];
+
+    let mut k: i16 = 0; // carry
+    for (j, wj) in w.iter_mut().enumerate() {
+        let uj = *u.get(j).unwrap_or(&0) as i16;
+        let vj = *v.get(j).unwrap_or(&0) as i16;
+        let t = uj - vj + k;
+
+        *wj = t.rem_euclid(10) as u8;
+        k = -((t < 0) as i16);
+    }
+
+    // a remaining carry means that u < v
+    if k != 0 {return None}
+
+    if w.is_empty() {w.push(0);}
+    ub_clean(&mut w);
+    Some(w)
+}
+
+
+
+
+
 
 
 /// Multiply 2 unsigned big ints u and v
@@ -275,6 +409,53 @@ pub fn ub_mul(u: &Vec<u8>, v: &Vec<u8>) -> Vec<u8> {
 
 
 
+/// Multiply an unsigned big int u by a single digit v.
+/// Simpler multiplication algorithm compared to [ub_mul] as v is only 1 digit,
+/// and it does not require v to be wrapped in a Vec
+///
+/// # Arguments
+///
+/// * `u` - unsigned big int (a Vec of digits, from least to most significant)
+/// * `v` - a digit (returns an error otherwise)
+///
+/// # Examples
+///
+/// ```
+/// use pilosa::core;
+///
+/// let n1 = vec![3, 6, 7, 2];     // 2763
+/// let n2 = vec![9, 9, 9];        // 999
+///
+/// assert_eq!(core::ub_shortmul(&n1, 0), Ok(vec![0]));
+/// assert_eq!(core::ub_shortmul(&n1, 1), Ok(vec![3, 6, 7, 2]));
+/// assert_eq!(core::ub_shortmul(&n1, 4), Ok(vec![2, 5, 0, 1, 1])); // 11052
+/// assert_eq!(core::ub_shortmul(&n2, 9), Ok(vec![1, 9, 9, 8]));    // 8991
+/// assert_eq!(core::ub_shortmul(&vec![9], 30), Err(core::CoreError::InvalidDigit(30)));
+/// ```
+pub fn ub_shortmul(u: &Vec<u8>, v: u8) -> Result<Vec<u8>, CoreError> {
+    if v > 9 {return Err(CoreError::InvalidDigit(v))}
+    if v == 0 {return Ok(vec![0]);}
+    if v == 1 {return Ok(ub_cleaned(u.clone()));}
+
+    let mut w = Vec::with_capacity(u.len() + 1);
+
+    let mut k = 0;
+    for d in u {
+        let t = d * v + k;
+        w.push(t % 10);
+        k = t / 10;
+    }
+    w.push(k); // final carry
+
+    ub_clean(&mut w);
+    Ok(w)
+}
+
+
+
+
+
+
 
 
 
@@ -284,7 +465,7 @@ pub fn ub_mul(u: &Vec<u8>, v: &Vec<u8>) -> Vec<u8> {
 /// 
 /// # Arguments
 /// * `u` - unsigned big ints (a Vec of digits, from least to most significant)
-/// * `v` - a digit != 0
+/// * `v` - a digit != 0 (returns an error otherwise)
 /// 
 /// # Examples
 /// 
@@ -298,10 +479,12 @@ pub fn ub_mul(u: &Vec<u8>, v: &Vec<u8>) -> Vec<u8> {
 /// assert_eq!(core::ub_shortdiv(n1.clone(), 3), Ok((vec![1, 2, 9], 0)));
 /// assert_eq!(core::ub_shortdiv(n2, 9), Ok((vec![0, 4], 4)));
 /// assert_eq!(core::ub_shortdiv(n3, 1), Ok((vec![0, 0, 1], 0)));
-/// assert!(core::ub_shortdiv(n1, 0).is_err());
+/// assert_eq!(core::ub_shortdiv(n1.clone(), 0), Err(core::CoreError::DivByZero));
+/// assert_eq!(core::ub_shortdiv(n1, 12), Err(core::CoreError::InvalidDigit(12)));
 /// ```
-pub fn ub_shortdiv(u: Vec<u8>, v: u8) -> Result<(Vec<u8>, u8), String> {
-    if v == 0 {return Err("Division by zero".to_string())}
+pub fn ub_shortdiv(u: Vec<u8>, v: u8) -> Result<(Vec<u8>, u8), CoreError> {
+    if v == 0 {return Err(CoreError::DivByZero)}
+    if v > 9 {return Err(CoreError::InvalidDigit(v))}
     if v == 1 {return Ok((ub_cleaned(u), 0))}
 
     let n = u.len();
@@ -331,6 +514,73 @@ pub fn ub_shortdiv(u: Vec<u8>, v: u8) -> Result<(Vec<u8>, u8), String> {
 
 
 
+/// Normalize the divisor of a division, as required by the division algorithm of the Art of Computer Programming.  
+/// Return `v * normaliser` and the normaliser, a digit chosen so that `v * normaliser` has the same amount of digits
+/// as `v` and its most significant digit is >= 5.  
+/// The dividend must be multiplied by the same normaliser. The quotient of the division is not changed,
+/// but the remainder must be divided by the normaliser (see [denormalize_remainder]).
+/// 
+/// # Arguments
+/// 
+/// * `v` - the divisor, a **cleaned** and non-zero unsigned big int (a Vec of digits, from least to most significant)
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// assert_eq!(core::normalize_divisor(&vec![4, 6, 3]), (vec![8, 2, 7], 2));  // 364 * 2 = 728
+/// assert_eq!(core::normalize_divisor(&vec![9, 1]), (vec![5, 9], 5));        // 19 * 5 = 95
+/// assert_eq!(core::normalize_divisor(&vec![2, 1]), (vec![6, 9], 8));        // 12 * 8 = 96
+/// assert_eq!(core::normalize_divisor(&vec![3, 6, 7, 2]), (vec![9, 8, 2, 8], 3)); // 2763 * 3 = 8289
+/// assert_eq!(core::normalize_divisor(&vec![0, 5]), (vec![0, 5], 1));        // already normalized
+/// ```
+pub fn normalize_divisor(v: &Vec<u8>) -> (Vec<u8>, u8) {
+    let n = v.len();
+    debug_assert!(v[n-1] != 0, "v must be cleaned and non-zero");
+
+    // will be > 1 if normalisation is needed
+    let mut normaliser = 9 / v[n-1];
+    let mut nv = ub_shortmul(v, normaliser).expect("the normaliser is a digit");
+
+    // we normalized too much (got one more digit), so we decrease the normaliser
+    while nv.len() > n {
+        normaliser -= 1;
+        nv = ub_shortmul(v, normaliser).expect("the normaliser is a digit");
+    }
+
+    (nv, normaliser)
+}
+
+
+
+
+/// Reverse the normalisation (see [normalize_divisor]) of the remainder of a division.
+/// Fails if the remainder is not a multiple of the normaliser, which means that it was not normalized with it.
+/// 
+/// # Arguments
+/// 
+/// * `r` - the normalized remainder, an unsigned big int (a Vec of digits, from least to most significant)
+/// * `normaliser` - the digit returned by [normalize_divisor]
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// assert_eq!(core::denormalize_remainder(vec![0, 3, 1], 2), Ok(vec![5, 6]));  // 130 / 2 = 65
+/// assert_eq!(core::denormalize_remainder(vec![1, 3, 1], 2), Err(core::CoreError::InexactRemainder(1)));
+/// ```
+pub fn denormalize_remainder(r: Vec<u8>, normaliser: u8) -> Result<Vec<u8>, CoreError> {
+    let (remainder, r0) = ub_shortdiv(r, normaliser)?;
+    if r0 != 0 {return Err(CoreError::InexactRemainder(r0))}
+
+    Ok(remainder)
+}
+
+
+
+
 /// Returns u // v and u % v.  
 /// If `v` is only 1 digit, it is preferable to use [ub_shortdiv] instead.
 /// 
@@ -351,54 +601,46 @@ pub fn ub_shortdiv(u: Vec<u8>, v: u8) -> Result<(Vec<u8>, u8), String> {
 /// let n4 = vec![0];              // 0
 /// 
 /// assert_eq!(core::ub_div(&n1, &n2), Ok((vec![7], vec![5, 1, 2])));
+/// assert_eq!(core::ub_div(&n1, &vec![7]), Err(core::CoreError::DivisorTooShort));
+/// assert_eq!(core::ub_div(&n3, &n1), Err(core::CoreError::DividendTooShort));
 /// //assert_eq!(core::ub_div(&n2, &n3));
 /// ```
-pub fn ub_div(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), String> {
-    assert_err!(v.len() > 1, "v needs to be of length 2 at least");
-    assert_err!(v.len() > 1, "v needs to be of length 2 at least");
-    assert_err!(u.len() >= v.len(), "m can't be negative");
+pub fn ub_div(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), CoreError> {
+    if v.len() <= 1 {return Err(CoreError::DivisorTooShort)}
+    if u.len() < v.len() {return Err(CoreError::DividendTooShort)}
 
-    let n = v.len();
+    // v[n-1] must be >= 5 to work with inner_div
+    // if it is not, we need to normalize the dividend and divisor so that v[n-1] >= 5
+    let (nv, normaliser) = normalize_divisor(v);
 
+    // multiply nu by normaliser too
+    let mut nu = ub_shortmul(u, normaliser)?;
 
+    // inner_div requires that nu is AT LEAST one digit longer than nv
+    // (nu can also be shorter than nv if u was not cleaned)
+    if nu.len() <= nv.len() {nu.resize(nv.len() + 1, 0);}
 
-    // v[n-1] must be < 5 to work with inner_div
-    // if it is not, we need to normalize the dividend and divisor so that v[n-1] >= 5
-    // we can later 
+    if nv[nv.len() - 1] < 5 {return Err(CoreError::NotNormalized(nv[nv.len() - 1]))}
 
 
-    // will be > 1 if normalisation is needed
-    let mut normaliser = 9 / v[n-1];
 
 
 
-    let mut nv = ub_mul(v, &vec![normaliser]);
 
 
+    // the quotient did not change after the normalisation
+    // only the remainder needs to be unnormalized
+    let (quotient, remainder) = inner_div(&nu, &nv);
 
-    // we normalized too much (got one more digit), so we substract v to nv and 1 to normaliser
-    while nv.len() > n && nv.last() != Some(&0) {
-        normaliser -= 1;
-        let mut rhs = v.clone();
-        rhs.push(0);
-        nv = ub_sub(nv, rhs).expect("internal error in ub_div");
 
+    let remainder = denormalize_remainder(remainder, normaliser)?;
 
-    }
+   Ok((quotient, remainder))
+}
 
-    // remove the last digit (which must be 0) if nv.len() > v.len()
-    if nv.len() > v.len() {
-        assert_err!(nv.last() == Some(&0), "last is not 0 after normal correction");
-        nv.pop();
-    }
 
-    // multiply nu by normaliser too
-    let mut nu = ub_mul(u, &vec![normaliser]);
 
-    // inner_div requires that nu is AT LEAST one digit longer than nv
-    if nu.len() == nv.len() {nu.push(0);}
 
-    assert_err!(nv[nv.len() - 1] >= 5, "last digit of nv = {} < 5", nv[nv.len() - 1]);
 
 
 
@@ -406,16 +648,106 @@ pub fn ub_div(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), String> {
 
 
 
-    // the quotient did not change after the normalisation
-    // only the remainder needs to be unnormalized
-    let (quotient, remainder) = inner_div(&nu, &nv);
 
+/// Returns u // v and u % v, both cleaned.  
+/// Unlike [ub_div], any divisor (except 0) is accepted: the function chooses between
+/// [ub_shortdiv] and [ub_div] depending on the length of v.
+/// 
+/// # Arguments
+/// 
+/// * `u` - the dividend of the operation, a **cleaned** unsigned bit int (a Vec of digits, from least to most significant)
+/// * `v` - the divisor, a **cleaned** unsigned big int too
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// let n1 = vec![3, 6, 7, 2];     // 2763
+/// let n2 = vec![4, 6, 3];        // 364
+/// let n3 = vec![7];              // 7
+/// let n4 = vec![0];              // 0
+/// 
+/// assert_eq!(core::ub_divmod(&n1, &n2), Ok((vec![7], vec![5, 1, 2])));
+/// assert_eq!(core::ub_divmod(&n1, &n3), Ok((vec![4, 9, 3], vec![5])));
+/// assert_eq!(core::ub_divmod(&n2, &n1), Ok((vec![0], vec![4, 6, 3])));
+/// assert_eq!(core::ub_divmod(&n1, &n4), Err(core::CoreError::DivByZero));
+/// ```
+pub fn ub_divmod(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), CoreError> {
+    if v.iter().all(|d| *d == 0) {return Err(CoreError::DivByZero)}
+
+    if ub_is_lower(u, v) {return Ok((vec![0], ub_cleaned(u.clone())))}
 
-    let (remainder, r0) = ub_shortdiv(remainder, normaliser).expect("internal error in ub_div"); // normaliser =/= 0 so no risk of error
+    let (mut q, mut r) = if v.len() == 1 {
+        let (q, r) = ub_shortdiv(u.clone(), v[0])?;
+        (q, vec![r])
+    } else {
+        ub_div(u, v)?
+    };
 
-    assert_err!(r0 == 0, "r0 = {r0} != 0");
+    ub_clean(&mut q);
+    ub_clean(&mut r);
+    Ok((q, r))
+}
 
-   Ok((quotient, remainder))
+
+
+
+
+/// Returns u % v, cleaned. See [ub_divmod]
+/// 
+/// # Arguments
+/// 
+/// * `u` - the dividend of the operation, a **cleaned** unsigned bit int (a Vec of digits, from least to most significant)
+/// * `v` - the divisor, a **cleaned** unsigned big int too
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// assert_eq!(core::ub_mod(&vec![3, 6, 7, 2], &vec![4, 6, 3]), Ok(vec![5, 1, 2])); // 2763 % 364 = 215
+/// assert_eq!(core::ub_mod(&vec![3, 6, 7, 2], &vec![7]), Ok(vec![5]));
+/// assert!(core::ub_mod(&vec![3, 6, 7, 2], &vec![0]).is_err());
+/// ```
+pub fn ub_mod(u: &Vec<u8>, v: &Vec<u8>) -> Result<Vec<u8>, CoreError> {
+    let (_, r) = ub_divmod(u, v)?;
+    Ok(r)
+}
+
+
+
+
+/// Returns the greatest common divisor of a and b, cleaned.  
+/// Based on the Euclidean algorithm. Note that `gcd(a, 0) = a`
+/// 
+/// # Arguments
+/// 
+/// * `a` & `b` - **cleaned** unsigned big ints (a Vec of digits, from least to most significant)
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::core;
+/// 
+/// assert_eq!(core::ub_gcd(&vec![8, 4], &vec![6, 3]), vec![2, 1]);          // gcd(48, 36) = 12
+/// assert_eq!(core::ub_gcd(&vec![3, 6, 7, 2], &vec![4, 6, 3]), vec![1]);    // gcd(2763, 364) = 1
+/// assert_eq!(core::ub_gcd(&vec![0, 7, 2], &vec![5, 2, 1]), vec![5]);       // gcd(270, 125) = 5
+/// assert_eq!(core::ub_gcd(&vec![7, 1], &vec![0]), vec![7, 1]);
+/// assert_eq!(core::ub_gcd(&vec![0], &vec![0]), vec![0]);
+/// ```
+pub fn ub_gcd(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut a = ub_cleaned(a.to_vec());
+    let mut b = ub_cleaned(b.to_vec());
+
+    while !b.iter().all(|d| *d == 0) {
+        let r = ub_mod(&a, &b).expect("internal error in ub_gcd"); // b != 0
+        a = b;
+        b = r;
+    }
+
+    if a.is_empty() {a.push(0);}
+    a
 }
 
 
@@ -431,7 +763,7 @@ pub fn ub_div(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), String> {
 
 /// Compute u / v and u % v.  
 /// This algorithm is based on the division algorithm in the Art of Computer Programming.
-/// However, the function expect the division to be normalised (i.e most significant digit of v > 5).
+/// However, the function expect the division to be normalised (i.e most significant digit of v >= 5).
 /// The function [ub_div] manages this normalisation.
 /// 
 /// # Arguments
@@ -442,7 +774,7 @@ pub fn ub_div(u: &Vec<u8>, v: &Vec<u8>) -> Result<(Vec<u8>, Vec<u8>), String> {
 /// # Conditions:
 /// `u.len() = m + n + 1 (n > 2, m >= 0)`
 /// `v.len() = n`
-/// `v[n-1] > 5`
+/// `v[n-1] >= 5`
 fn inner_div(u: &Vec<u8>, v: &Vec<u8>) -> (Vec<u8>, Vec<u8>) {
     debug_assert!(v.len() > 1, "v needs to be of length 2 at least");
     debug_assert!(u.len() >= v.len(), "m can't be negative");
@@ -454,7 +786,7 @@ fn inner_div(u: &Vec<u8>, v: &Vec<u8>) -> (Vec<u8>, Vec<u8>) {
     let n = v.len();
     let m = u.len() - n - 1;
 
-    debug_assert!(v[n-1] > 5, "v[n-1] should be > 5");
+    debug_assert!(v[n-1] >= 5, "v[n-1] should be >= 5");
 
     
 
@@ -463,14 +795,7 @@ fn inner_div(u: &Vec<u8>, v: &Vec<u8>) -> (Vec<u8>, Vec<u8>) {
 
 
 
-    println!("n: {}  m: {}", n, m);
-
-
-
-
     for j in (0..m+1).rev() { // j goes from m to 0 (included)
-        println!("value of j: {j}");
-
         // estimation of q (called q_est) and r (r_est)
         let mut q_est = (u[j+n] * 10 + u[j+n-1]) / v[n-1];
         let mut r_est = (u[j+n] * 10 + u[j+n-1]).rem_euclid(v[n-1]);
@@ -496,8 +821,8 @@ fn inner_div(u: &Vec<u8>, v: &Vec<u8>) -> (Vec<u8>, Vec<u8>) {
         // and remember the borrowing that occured for later use
         
         let u_slice = u[j..j+n+1].to_vec();                          // (Uj+n Uj+n-1 ... Uj) of length n+1
-        let mut v_slice = ub_mul(v, &vec![q_est]);       // q_est(Vn-1 ... V1 V0) of length n+1
-        v_slice.resize(n+1, 0); // the result of the ub_mul can be [0], so we resize to n+1
+        let mut v_slice = ub_shortmul(v, q_est).expect("q_est is a digit after its correction"); // q_est(Vn-1 ... V1 V0) of length n+1
+        v_slice.resize(n+1, 0); // the result of ub_shortmul can be [0], so we resize to n+1
 
 
 
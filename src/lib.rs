@@ -7,6 +7,12 @@
 mod macros;
 pub mod core;
 mod big_num;
+mod number;
 
 pub use big_num::BigNum;
-pub use big_num::FLOAT_PRECISION;
\ No newline at end of file
+pub use big_num::LongDivision;
+pub use big_num::Powers;
+pub use big_num::PowerCache;
+pub use big_num::RoundingMode;
+pub use big_num::FLOAT_PRECISION;
+pub use number::Number;
\ No newline at end of file
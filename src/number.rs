@@ -0,0 +1,50 @@
+//! Trait used to write algorithms generic over [BigNum] and primitive floats
+
+
+use std::ops::{Add, Sub, Mul, Div};
+
+use crate::BigNum;
+
+
+/// A number supporting the basic arithmetic operations and comparisons.  
+/// Implemented by [BigNum] and `f64`, so the same algorithm can be used with both.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use pilosa::{BigNum, Number};
+/// 
+/// // Newton's method to compute the square root of x
+/// fn newton_sqrt<T: Number>(x: &T, iterations: u32) -> T {
+///     let two = T::one() + T::one();
+///     let mut res = T::one();
+///     for _ in 0..iterations {
+///         res = (res.clone() + x.clone() / res) / two.clone();
+///     }
+///     res
+/// }
+/// 
+/// let n = newton_sqrt(&BigNum::from(2), 6);
+/// assert_eq!(n.to_string(), "1.414213562373095");
+/// assert!((newton_sqrt(&2.0, 6) - n.to_f64()).abs() < 1e-14);
+/// assert!(newton_sqrt(&BigNum::zero(), 3) < BigNum::one());
+/// ```
+pub trait Number: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + PartialOrd + Clone {
+    /// Return the neutral element of the addition
+    fn zero() -> Self;
+    /// Return the neutral element of the multiplication
+    fn one() -> Self;
+}
+
+
+
+impl Number for BigNum {
+    fn zero() -> Self {BigNum::zero()}
+    fn one() -> Self {BigNum::one()}
+}
+
+
+impl Number for f64 {
+    fn zero() -> Self {0.0}
+    fn one() -> Self {1.0}
+}